@@ -3,42 +3,180 @@
 //#########################
 
     use std::collections::HashMap;
+    use std::hash::Hasher;
     use std::any::Any;
+    use std::fmt::Debug;
+    use std::mem;
     use std::rc::Rc;
     use std::cell::RefCell;
 
     use crate::entities::Entity;
+    use crate::errors::ComponentType;
+    use crate::hashes::StateHasher;
 
 
 //#######################
 // D E F I N I T I O N S
 //#######################
 
+    type DebugCell   = fn(&dyn ComponentCell) -> Option<String>;
+    type ReflectCell = fn(&dyn ComponentCell) -> Option<Vec<(&'static str, String)>>;
+    type HashCell    = fn(&dyn ComponentCell) -> Option<u64>;
+    type EqCells     = fn(&dyn ComponentCell, &dyn ComponentCell) -> Option<bool>;
+
+
     pub(crate) trait ComponentCell {
-        fn as_any(&self) -> &dyn Any;
+        fn as_any(&self)          -> &dyn Any;
+        fn clone_cell(&self)      -> Box<dyn ComponentCell>;
+        fn clone_value(&self)     -> Box<dyn ComponentCell>;
+        fn assign(&self, value: &dyn ComponentCell);
+        fn cell_id(&self)         -> usize;
+        fn is_borrowed_mut(&self) -> bool;
     } // trait ..
 
     pub(crate) trait ComponentColumn: Any {
-        fn as_any(&self)         -> &dyn Any;
-        fn as_any_mut(&mut self) -> &mut dyn Any;
-        fn remove_entity(&mut self, entity: Entity);
+        fn as_any(&self) -> &dyn Any;
+        fn insert_cell(&mut self, entity: Entity, cell: Box<dyn ComponentCell>) -> Option<Box<dyn ComponentCell>>;
+        fn remove_cell(&mut self, entity: Entity) -> Option<Box<dyn ComponentCell>>;
+        fn get_cell(&self, entity: Entity) -> Option<&dyn ComponentCell>;
+        fn entities(&self) -> Vec<Entity>;
+        fn for_each_cell(&self, f: &mut dyn FnMut(Entity, &dyn ComponentCell));
     } // trait ..
 
 
     pub trait Component: Any + Clone {}
 
 
+    pub trait Reflect: Component {
+        fn fields(&self) -> Vec<(&'static str, String)>;
+    } // trait ..
+
+
+    pub trait HashComponent: Component {
+        fn hash_component<H: Hasher>(&self, state: &mut H);
+    } // trait ..
+
+
+    #[derive(Clone, Copy)]
+    pub(crate) struct ComponentInfo {
+        pub(crate) kind:    ComponentType,
+        pub(crate) size:    usize,
+        pub(crate) debug:   Option<DebugCell>,
+        pub(crate) reflect: Option<ReflectCell>,
+        pub(crate) hash:    Option<HashCell>,
+        pub(crate) eq:      Option<EqCells>,
+    } // struct ..
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ComponentDescription {
+        pub name:   &'static str,
+        pub size:   usize,
+        pub value:  Option<String>,
+        pub fields: Vec<(&'static str, String)>,
+    } // struct ..
+
+
 //###############################
 // I M P L E M E N T A T I O N S
 //###############################
 
     impl<C: 'static + Component> ComponentCell for Rc<RefCell<C>> {
-        fn as_any(&self) -> &dyn Any { self }
+        fn as_any(&self)          -> &dyn Any               { self }
+        fn clone_cell(&self)      -> Box<dyn ComponentCell> { Box::new(self.clone()) }
+        fn clone_value(&self)     -> Box<dyn ComponentCell> { Box::new(Rc::new(RefCell::new(self.borrow().clone()))) }
+        fn cell_id(&self)         -> usize                  { Rc::as_ptr(self) as usize }
+        fn is_borrowed_mut(&self) -> bool                   { self.try_borrow().is_err() }
+
+        fn assign(&self, value: &dyn ComponentCell) {
+            if let Some(value) = value.as_any().downcast_ref::<Rc<RefCell<C>>>() {
+                if !Rc::ptr_eq(self, value) { self.borrow_mut().clone_from(&value.borrow()); }
+            } // if ..
+        } // fn ..
     } // impl ..
 
 
     impl<C: 'static + Component> ComponentColumn for HashMap<Entity, Rc<RefCell<C>>> {
-        fn as_any(&self)         -> &dyn Any        { self }
-        fn as_any_mut(&mut self) -> &mut dyn Any    { self }
-        fn remove_entity(&mut self, entity: Entity) { self.remove(&entity); }
+        fn as_any(&self)                     -> &dyn Any                      { self }
+        fn get_cell(&self, entity: Entity)   -> Option<&dyn ComponentCell>    { self.get(&entity).map(|cell| cell as &dyn ComponentCell) }
+        fn remove_cell(&mut self, entity: Entity) -> Option<Box<dyn ComponentCell>> { self.remove(&entity).map(|cell| Box::new(cell) as Box<dyn ComponentCell>) }
+        fn entities(&self)                   -> Vec<Entity>                   { self.keys().copied().collect() }
+
+
+        fn for_each_cell(&self, f: &mut dyn FnMut(Entity, &dyn ComponentCell)) {
+            self.iter().for_each(|(entity, cell)| f(*entity, cell));
+        } // fn ..
+
+
+        fn insert_cell(&mut self, entity: Entity, cell: Box<dyn ComponentCell>) -> Option<Box<dyn ComponentCell>> {
+            cell.as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .and_then(|cell| self.insert(entity, cell.clone()))
+                .map(|cell| Box::new(cell) as Box<dyn ComponentCell>)
+        } // fn ..
+    } // impl ..
+
+
+    impl ComponentInfo {
+        pub(crate) fn new<C: Component>() -> Self {
+            ComponentInfo {
+                kind:    ComponentType::of::<C>(),
+                size:    mem::size_of::<C>(),
+                debug:   None,
+                reflect: None,
+                hash:    None,
+                eq:      None,
+            } // ComponentInfo
+        } // fn ..
+
+
+        pub(crate) fn with_debug<C: Component + Debug>(&mut self) {
+            self.debug = Some(|cell| cell
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .and_then(|cell| cell.try_borrow().ok().map(|component| format!("{:?}", component))));
+        } // fn ..
+
+
+        pub(crate) fn with_reflect<C: Reflect>(&mut self) {
+            self.reflect = Some(|cell| cell
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .and_then(|cell| cell.try_borrow().ok().map(|component| component.fields())));
+        } // fn ..
+
+
+        pub(crate) fn with_hash<C: HashComponent>(&mut self) {
+            self.hash = Some(|cell| cell
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .and_then(|cell| cell.try_borrow().ok().map(|component| {
+
+                    let mut state = StateHasher::default();
+                    component.hash_component(&mut state);
+                    state.finish()
+
+                }))); // Some()
+        } // fn ..
+
+
+        pub(crate) fn with_eq<C: Component + PartialEq>(&mut self) {
+            self.eq = Some(|cell, other| cell
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .zip(other.as_any().downcast_ref::<Rc<RefCell<C>>>())
+                .and_then(|(cell, other)| cell.try_borrow().ok().zip(other.try_borrow().ok()))
+                .map(|(cell, other)| *cell == *other)
+            ); // Some()
+        } // fn ..
+
+
+        pub(crate) fn describe(&self, cell: Option<&dyn ComponentCell>) -> ComponentDescription {
+            ComponentDescription {
+                name:   self.kind.name(),
+                size:   self.size,
+                value:  cell.zip(self.debug).and_then(|(cell, debug)| debug(cell)),
+                fields: cell.zip(self.reflect).and_then(|(cell, reflect)| reflect(cell)).unwrap_or_default(),
+            } // ComponentDescription
+        } // fn ..
     } // impl ..
@@ -2,13 +2,16 @@
 // D E P E N D E N C I E S
 //#########################
 
-    use std::fmt::Debug;
+    use std::fmt::{self, Debug};
     use std::rc::Rc;
     use std::cell::RefCell;
     use std::hash::Hash;
 
-    use crate::worlds::{World, EcsErr};
+    use crate::worlds::World;
+    use crate::errors::EcsErr;
     use crate::components::Component;
+    use crate::prefabs::Prefab;
+    use crate::names::{self, Name};
 
     use rusty_toolkit::BitField;
 
@@ -17,8 +20,8 @@
 // D E F I N I T I O N S
 //#######################
 
-    #[derive(Clone, Copy, Hash, PartialEq, Eq, Default, Debug)]
-    pub struct Entity(EntityId);
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+    pub struct Entity(pub(crate) EntityId);
 
 
     pub struct EntityBuilder<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> {
@@ -35,6 +38,13 @@
 // I M P L E M E N T A T I O N S
 //###############################
 
+    impl Debug for Entity {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", names::named_entity(*self, names::debug_name(*self).as_deref()))
+        } // fn ..
+    } // impl ..
+
+
     impl<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> EntityBuilder<'world, B, F, P> {
         pub(crate) fn new(
             id:    EntityId,
@@ -50,6 +60,9 @@
         } // fn ..
 
 
+        pub fn with_name<T: Into<String>>(self, name: T) -> Result<Self, EcsErr<B, F, P>> { self.with_component(Name(name.into())) }
+
+
         pub fn with_shared_component<C: Component>(mut self, component: &Rc<RefCell<C>>) -> Result<Self, EcsErr<B, F, P>> {
 
             self.world.add_shared_component_to_entity_builder(component, self.entity, &mut self.bit_mask)?;
@@ -70,8 +83,37 @@
         } // fn ..
 
 
+        pub fn with_parent(self, parent: Entity) -> Result<Self, EcsErr<B, F, P>> {
+
+            self.world.get_entity_bit_mask(parent)?;
+            self.world.link_entity_parent(self.entity, parent);
+            Ok(self)
+
+        } // fn ..
+
+
+        pub fn with_prefab(mut self, prefab: &Prefab<B, F, P>) -> Result<Self, EcsErr<B, F, P>> {
+
+            let mut bit_mask = self.bit_mask;
+            self.world.atomically(|world| prefab.add_to_entity_builder(world, self.entity, &mut bit_mask))?;
+
+            self.bit_mask = bit_mask;
+            Ok(self)
+
+        } // fn ..
+
+
         pub fn build(self) -> Entity {
 
+            self.world.add_entity(self.entity, self.bit_mask);
+            self.world.debug_validate("new_entity");
+            self.entity
+
+        } // fn ..
+
+
+        pub(crate) fn build_child(self) -> Entity {
+
             self.world.add_entity(self.entity, self.bit_mask);
             self.entity
 
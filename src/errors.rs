@@ -0,0 +1,314 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::any::TypeId;
+    use std::fmt::{Debug, Display};
+    use std::fmt;
+    use std::error::Error;
+
+    use crate::components::Component;
+    use crate::entities::Entity;
+    use crate::names::named_entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct ComponentType {
+        id:   TypeId,
+        name: &'static str,
+    } // struct ..
+
+
+    #[derive(Debug)]
+    pub enum EcsErr<B: BitField, F: BitField, P> {
+        MissingEntity(Entity),
+        MissingEntityGroup(Vec<Entity>),
+        ExistingEntity(Entity),
+        MissingComponent(ComponentType),
+        MissingComponentPtr(P),
+        MissingFlag(F),
+        MissingComponentToEntity(ComponentType, Entity),
+        FailedToDowncastComponentCol(ComponentType, B),
+        FailedToDowncastPtr(P, ComponentType),
+        BitFieldRangeTooSmall(usize, usize),
+        EmptyFlagRange(F),
+        OverlappingFlagRanges(F, F),
+        CyclicParent(Entity, Entity),
+        InvalidScene(usize, String),
+        InvalidJournal(usize, String),
+        MissingSnapshot(u64),
+        BorrowedComponent(ComponentType, Entity),
+        ConflictingQueries(&'static str, &'static str, ComponentType),
+        UndeclaredAccess(&'static str, &'static str, ComponentType),
+        NotSingleQuery(&'static str, usize),
+        ForeignQueryState(&'static str),
+        MissingIndex(ComponentType, &'static str),
+        DuplicateIndexKey(ComponentType, Entity, Entity),
+        OpenTransaction,
+        InvalidBuilder(Vec<BuildDiagnostic<F, P>>),
+        Operation(&'static str, Box<EcsErr<B, F, P>>),
+        Named(Box<EcsErr<B, F, P>>, Vec<(Entity, String)>),
+    } // enum ..
+
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum EcsErrKind {
+        MissingEntity,
+        ExistingEntity,
+        MissingComponent,
+        MissingComponentPtr,
+        MissingFlag,
+        MissingComponentToEntity,
+        FailedToDowncast,
+        BitFieldRangeTooSmall,
+        InvalidFlagRange,
+        CyclicParent,
+        InvalidScene,
+        InvalidJournal,
+        MissingSnapshot,
+        BorrowConflict,
+        UndeclaredAccess,
+        NotSingleQuery,
+        ForeignQueryState,
+        MissingIndex,
+        DuplicateIndexKey,
+        OpenTransaction,
+        InvalidBuilder,
+    } // enum ..
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum BuildDiagnostic<F: BitField, P> {
+        DuplicateComponent(ComponentType),
+        DuplicateComponentPtr(P),
+        DuplicateFlag(F),
+        OverlappingFlags(F, F),
+        InvalidCellSize(ComponentType),
+    } // enum ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl ComponentType {
+        pub fn of<C: Component>() -> Self {
+            ComponentType {
+                id:   TypeId::of::<C>(),
+                name: std::any::type_name::<C>(),
+            } // ComponentType
+        } // fn ..
+
+
+        pub fn id(&self)   -> TypeId       { self.id }
+        pub fn name(&self) -> &'static str { self.name }
+
+    } // impl ..
+
+
+    impl Display for ComponentType {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.name) }
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P> EcsErr<B, F, P> {
+        pub fn kind(&self) -> EcsErrKind {
+            match self {
+                EcsErr::MissingEntity(_)                   => EcsErrKind::MissingEntity,
+                EcsErr::MissingEntityGroup(_)              => EcsErrKind::MissingEntity,
+                EcsErr::ExistingEntity(_)                  => EcsErrKind::ExistingEntity,
+                EcsErr::MissingComponent(_)                => EcsErrKind::MissingComponent,
+                EcsErr::MissingComponentPtr(_)             => EcsErrKind::MissingComponentPtr,
+                EcsErr::MissingFlag(_)                     => EcsErrKind::MissingFlag,
+                EcsErr::MissingComponentToEntity(_, _)     => EcsErrKind::MissingComponentToEntity,
+                EcsErr::FailedToDowncastComponentCol(_, _) => EcsErrKind::FailedToDowncast,
+                EcsErr::FailedToDowncastPtr(_, _)          => EcsErrKind::FailedToDowncast,
+                EcsErr::BitFieldRangeTooSmall(_, _)        => EcsErrKind::BitFieldRangeTooSmall,
+                EcsErr::EmptyFlagRange(_)                  => EcsErrKind::InvalidFlagRange,
+                EcsErr::OverlappingFlagRanges(_, _)        => EcsErrKind::InvalidFlagRange,
+                EcsErr::CyclicParent(_, _)                 => EcsErrKind::CyclicParent,
+                EcsErr::InvalidScene(_, _)                 => EcsErrKind::InvalidScene,
+                EcsErr::InvalidJournal(_, _)               => EcsErrKind::InvalidJournal,
+                EcsErr::MissingSnapshot(_)                 => EcsErrKind::MissingSnapshot,
+                EcsErr::BorrowedComponent(_, _)            => EcsErrKind::BorrowConflict,
+                EcsErr::ConflictingQueries(_, _, _)        => EcsErrKind::BorrowConflict,
+                EcsErr::UndeclaredAccess(_, _, _)          => EcsErrKind::UndeclaredAccess,
+                EcsErr::NotSingleQuery(_, _)               => EcsErrKind::NotSingleQuery,
+                EcsErr::ForeignQueryState(_)               => EcsErrKind::ForeignQueryState,
+                EcsErr::MissingIndex(_, _)                 => EcsErrKind::MissingIndex,
+                EcsErr::DuplicateIndexKey(_, _, _)         => EcsErrKind::DuplicateIndexKey,
+                EcsErr::OpenTransaction                    => EcsErrKind::OpenTransaction,
+                EcsErr::InvalidBuilder(_)                  => EcsErrKind::InvalidBuilder,
+                EcsErr::Operation(_, err)                  => err.kind(),
+                EcsErr::Named(err, _)                      => err.kind(),
+            } // match ..
+        } // fn ..
+
+
+        pub fn operation(&self) -> Option<&'static str> {
+            match self {
+                EcsErr::Operation(operation, _) => Some(operation),
+                _                               => None,
+            } // match ..
+        } // fn ..
+
+
+        pub fn entity(&self) -> Option<Entity> {
+            match self {
+                EcsErr::MissingEntity(e)               => Some(*e),
+                EcsErr::ExistingEntity(e)              => Some(*e),
+                EcsErr::MissingComponentToEntity(_, e) => Some(*e),
+                EcsErr::CyclicParent(e, _)             => Some(*e),
+                EcsErr::BorrowedComponent(_, e)        => Some(*e),
+                EcsErr::DuplicateIndexKey(_, e, _)     => Some(*e),
+                EcsErr::Operation(_, err)              => err.entity(),
+                EcsErr::Named(err, _)                  => err.entity(),
+                _                                      => None,
+            } // match ..
+        } // fn ..
+
+
+        pub fn component(&self) -> Option<ComponentType> {
+            match self {
+                EcsErr::MissingComponent(c)                => Some(*c),
+                EcsErr::MissingComponentToEntity(c, _)     => Some(*c),
+                EcsErr::FailedToDowncastComponentCol(c, _) => Some(*c),
+                EcsErr::FailedToDowncastPtr(_, c)          => Some(*c),
+                EcsErr::BorrowedComponent(c, _)            => Some(*c),
+                EcsErr::ConflictingQueries(_, _, c)        => Some(*c),
+                EcsErr::UndeclaredAccess(_, _, c)          => Some(*c),
+                EcsErr::MissingIndex(c, _)                 => Some(*c),
+                EcsErr::DuplicateIndexKey(c, _, _)         => Some(*c),
+                EcsErr::Operation(_, err)                  => err.component(),
+                EcsErr::Named(err, _)                      => err.component(),
+                _                                          => None,
+            } // match ..
+        } // fn ..
+
+
+        pub fn names(&self) -> &[(Entity, String)] {
+            match self {
+                EcsErr::Operation(_, err) => err.names(),
+                EcsErr::Named(_, names)   => names,
+                _                         => &[],
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn during(self, operation: &'static str) -> Self {
+            match self {
+                EcsErr::Operation(_, err) => EcsErr::Operation(operation, err),
+                err                       => EcsErr::Operation(operation, Box::new(err)),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn entities(&self) -> Vec<Entity> {
+            match self {
+                EcsErr::MissingEntityGroup(e)      => e.clone(),
+                EcsErr::CyclicParent(e, p)         => vec![*e, *p],
+                EcsErr::DuplicateIndexKey(_, e, o) => vec![*e, *o],
+                EcsErr::Operation(_, err)          => err.entities(),
+                err                                => err.entity().into_iter().collect(),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn named(self, names: Vec<(Entity, String)>) -> Self {
+            match self {
+                EcsErr::Operation(operation, err)  => EcsErr::Operation(operation, Box::new(err.named(names))),
+                err @ EcsErr::Named(_, _)          => err,
+                err if names.is_empty()            => err,
+                err                                => EcsErr::Named(Box::new(err), names),
+            } // match ..
+        } // fn ..
+
+    } // impl ..
+
+
+    impl<B: BitField + Debug + 'static, F: BitField + Debug + 'static, P: Debug + 'static> Error for EcsErr<B, F, P> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                EcsErr::Operation(_, err) => Some(err.as_ref()),
+                _                         => None,
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField + Debug, P: Debug> Display for EcsErr<B, F, P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.message(&[])) }
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField + Debug, P: Debug> EcsErr<B, F, P> {
+        fn message(&self, names: &[(Entity, String)]) -> String {
+
+            let entity = |entity: &Entity| match names.iter().find(|(named, _)| named == entity) {
+                Some((_, name)) => named_entity(*entity, Some(name)),
+                None            => format!("{:?}", entity),
+            }; // let ..
+
+            match self {
+                EcsErr::MissingEntity(e)                   => format!("The entity `{}` is not registered!", entity(e)),
+                EcsErr::MissingEntityGroup(e)              => format!("The entities `[{}]` are not registered!", e.iter().map(entity).collect::<Vec<String>>().join(", ")),
+                EcsErr::ExistingEntity(e)                  => format!("The entity `{}` is already registered!", entity(e)),
+                EcsErr::MissingComponent(c)                => format!("The component `{}` is not registered!", c),
+                EcsErr::MissingComponentPtr(p)             => format!("The component pointer `{:?}` is not registered!", p),
+                EcsErr::MissingFlag(b)                     => format!("The flag `{:x}` is not registerd!", b),
+                EcsErr::FailedToDowncastComponentCol(c, b) => format!("Failed to downcast the `{:x}` component column to `{}`!", b, c),
+                EcsErr::FailedToDowncastPtr(p, c)          => format!("Failed to downcast the `{:?}` component pointer to `{}`!", p, c),
+                EcsErr::MissingComponentToEntity(c, e)     => format!("The entity `{}` has no registered component `{}`!", entity(e), c),
+                EcsErr::BitFieldRangeTooSmall(s, r)        => format!("The bitfield's range is too low: `{} > {}`!", s, r),
+                EcsErr::EmptyFlagRange(b)                  => format!("The flag `{:x}` has an empty range!", b),
+                EcsErr::OverlappingFlagRanges(a, b)        => format!("The flag `{:x}` overlaps the flag `{:x}`!", a, b),
+                EcsErr::CyclicParent(e, p)                 => format!("The entity `{}` cannot be parented to its descendant `{}`!", entity(e), entity(p)),
+                EcsErr::InvalidScene(l, m)                 => format!("The scene is invalid at line {}: {}!", l, m),
+                EcsErr::InvalidJournal(l, m)               => format!("The journal is invalid at entry {}: {}!", l, m),
+                EcsErr::MissingSnapshot(t)                 => format!("The snapshot of tick `{}` is not registered!", t),
+                EcsErr::BorrowedComponent(c, e)            => format!("The component `{}` of the entity `{}` is already borrowed!", c, entity(e)),
+                EcsErr::ConflictingQueries(q, o, c)        => format!("The query `{}` conflicts with the live query `{}` over the component `{}`!", q, o, c),
+                EcsErr::UndeclaredAccess(q, a, c)          => format!("The query `{}` did not declare a {} access to the component `{}`!", q, a, c),
+                EcsErr::NotSingleQuery(q, n)               => format!("The query `{}` matches {} entities instead of a single one!", q, n),
+                EcsErr::ForeignQueryState(q)               => format!("The query state `{}` was built from another world!", q),
+                EcsErr::MissingIndex(c, k)                 => format!("The component `{}` has no index keyed by `{}`!", c, k),
+                EcsErr::DuplicateIndexKey(c, e, o)         => format!("The entity `{}` has the same unique `{}` index key as the entity `{}`!", entity(e), c, entity(o)),
+                EcsErr::OpenTransaction                    => String::from("The history cannot be stepped inside a transaction!"),
+                EcsErr::InvalidBuilder(d)                  => format!("The world builder is invalid: {}", d
+                    .iter()
+                    .map(|diagnostic| diagnostic.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+                ), // => ..
+                EcsErr::Operation(o, err)                  => match err.names() {
+                    []    => format!("The operation `{}` failed!", o),
+                    names => format!("The operation `{}` failed for {}!", o, names
+                        .iter()
+                        .map(|(named, name)| format!("`{}`", named_entity(*named, Some(name))))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                    ), // => ..
+                }, // => ..
+                EcsErr::Named(err, names)                  => err.message(names),
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<F: BitField, P: Debug> Display for BuildDiagnostic<F, P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", match self {
+                BuildDiagnostic::DuplicateComponent(c)     => format!("The component `{}` has been discarded as it was already registered!", c),
+                BuildDiagnostic::DuplicateComponentPtr(p)  => format!("The component pointer `{:?}` has been discarded as it was already registered!", p),
+                BuildDiagnostic::DuplicateFlag(b)          => format!("The flag `{:x}` has been discarded as it was already registered!", b),
+                BuildDiagnostic::OverlappingFlags(a, b)    => format!("The flag `{:x}` overlaps the flag `{:x}`!", a, b),
+                BuildDiagnostic::InvalidCellSize(c)        => format!("The spatial index of `{}` has been discarded as its cell size is not a positive finite number!", c),
+            }) // write()
+        } // fn ..
+    } // impl ..
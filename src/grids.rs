@@ -0,0 +1,280 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, HashSet};
+    use std::cmp::Ordering;
+    use std::any::Any;
+    use std::fmt::Debug;
+    use std::hash::Hash;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::marker::PhantomData;
+
+    use crate::worlds::World;
+    use crate::errors::{EcsErr, ComponentType};
+    use crate::components::{Component, ComponentCell};
+    use crate::entities::Entity;
+    use crate::indexes::ComponentIndex;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait Position: Component {
+        fn position(&self) -> [f32; 3];
+    } // trait ..
+
+
+    pub(crate) struct SpatialIndex<C: Position> {
+        cell_size: f32,
+        cells:     HashMap<[i32; 3], Vec<Entity>>,
+        positions: HashMap<Entity, ([f32; 3], usize)>,
+        entities:  HashMap<usize, Vec<Entity>>,
+        component: PhantomData<C>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<C: Position> SpatialIndex<C> {
+        pub(crate) fn new(cell_size: f32) -> Self {
+            SpatialIndex {
+                cell_size,
+                cells:     HashMap::default(),
+                positions: HashMap::default(),
+                entities:  HashMap::default(),
+                component: PhantomData,
+            } // SpatialIndex
+        } // fn ..
+
+
+        fn cell_of(&self, position: [f32; 3]) -> [i32; 3] { position.map(|coordinate| (coordinate / self.cell_size).floor() as i32) }
+
+
+        fn position_of(&self, cell: &dyn ComponentCell) -> Option<[f32; 3]> {
+            cell
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .and_then(|cell| cell.try_borrow().ok().map(|component| component.position()))
+        } // fn ..
+
+
+        fn lent_positions(&self, lent_cells: &[Box<dyn ComponentCell>]) -> (HashSet<Entity>, Vec<(Entity, [f32; 3])>) {
+
+            let stale = lent_cells
+                .iter()
+                .flat_map(|cell| self.entities_of_cell(cell.as_ref()))
+                .collect();
+            let fresh = lent_cells
+                .iter()
+                .filter_map(|cell| self.position_of(cell.as_ref()).map(|position| (cell, position)))
+                .flat_map(|(cell, position)| self.entities_of_cell(cell.as_ref()).into_iter().map(move |entity| (entity, position)))
+                .collect();
+
+            (stale, fresh)
+
+        } // fn ..
+
+
+        fn within_cells(&self, min: [i32; 3], max: [i32; 3], filter: impl Fn([i32; 3]) -> bool) -> Vec<(Entity, [f32; 3])> {
+
+            let inside = |cell: [i32; 3]| (0..3).all(|axis| min[axis] <= cell[axis] && cell[axis] <= max[axis]) && filter(cell);
+            let cells  = match volume(min, max) > self.cells.len() {
+                true  => self.cells
+                    .iter()
+                    .filter(|(cell, _)| inside(**cell))
+                    .map(|(_, entities)| entities)
+                    .collect::<Vec<&Vec<Entity>>>(),
+                false => (min[0]..=max[0])
+                    .flat_map(|x| (min[1]..=max[1]).flat_map(move |y| (min[2]..=max[2]).map(move |z| [x, y, z])))
+                    .filter(|cell| inside(*cell))
+                    .filter_map(|cell| self.cells.get(&cell))
+                    .collect::<Vec<&Vec<Entity>>>(),
+            }; // let ..
+
+            cells
+                .into_iter()
+                .flatten()
+                .filter_map(|entity| self.positions.get(entity).map(|(position, _)| (*entity, *position)))
+                .collect()
+
+        } // fn ..
+
+
+        fn nearest(&self, point: [f32; 3], skip: impl Fn(Entity) -> bool) -> Option<(f32, Entity)> {
+
+            let center      = self.cell_of(point);
+            let mut nearest = None;
+
+            for ring in 0u32.. {
+
+                let min        = center.map(|coordinate| coordinate.saturating_sub_unsigned(ring));
+                let max        = center.map(|coordinate| coordinate.saturating_add_unsigned(ring));
+                let exhaustive = volume(min, max) > self.cells.len();
+                let candidates = match exhaustive {
+                    true  => self.positions.iter().map(|(entity, (position, _))| (*entity, *position)).collect(),
+                    false => self.within_cells(min, max, |cell| (0..3).any(|axis| cell[axis].abs_diff(center[axis]) == ring)),
+                }; // let ..
+
+                nearest = candidates
+                    .into_iter()
+                    .filter(|(entity, _)| !skip(*entity))
+                    .map(|(entity, position)| (distance(point, position), entity))
+                    .chain(nearest)
+                    .min_by(closer);
+
+                if exhaustive || nearest.is_some_and(|(distance, _)| distance <= ring as f32 * self.cell_size) { break }
+
+            } // for ..
+
+            nearest
+
+        } // fn ..
+    } // impl ..
+
+
+    impl<C: Position> ComponentIndex for SpatialIndex<C> {
+        fn as_any(&self) -> &dyn Any { self }
+        fn unique(&self) -> bool     { false }
+
+
+        fn insert(&mut self, entity: Entity, cell: &dyn ComponentCell) -> Option<Entity> {
+
+            let position = self.position_of(cell)?;
+            self.cells.entry(self.cell_of(position)).or_default().push(entity);
+            self.entities.entry(cell.cell_id()).or_default().push(entity);
+            self.positions.insert(entity, (position, cell.cell_id()));
+
+            None
+
+        } // fn ..
+
+
+        fn remove(&mut self, entity: Entity) {
+            if let Some((position, cell_id)) = self.positions.remove(&entity) {
+
+                let cell = self.cell_of(position);
+                if let Some(entities) = self.cells.get_mut(&cell) {
+
+                    entities.retain(|other| *other != entity);
+                    if entities.is_empty() { self.cells.remove(&cell); }
+
+                } // if ..
+
+                if let Some(entities) = self.entities.get_mut(&cell_id) {
+
+                    entities.retain(|other| *other != entity);
+                    if entities.is_empty() { self.entities.remove(&cell_id); }
+
+                } // if ..
+            } // if ..
+        } // fn ..
+
+
+        fn entities_of_cell(&self, cell: &dyn ComponentCell) -> Vec<Entity> {
+            self.entities
+                .get(&cell.cell_id())
+                .cloned()
+                .unwrap_or_default()
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn within_radius<C: Position>(&self, center: [f32; 3], radius: f32) -> Result<Vec<Entity>, EcsErr<B, F, P>> {
+            self.during("within_radius", |world| {
+
+                let index          = world.spatial_index::<C>()?;
+                let (stale, fresh) = index.lent_positions(&world.lent_cells_of(world.component_bit_mask::<C>()?));
+                let min            = index.cell_of(center.map(|coordinate| coordinate - radius));
+                let max            = index.cell_of(center.map(|coordinate| coordinate + radius));
+
+                Ok(world.ordered_entities(index
+                    .within_cells(min, max, |_| true)
+                    .into_iter()
+                    .filter(|(entity, _)| !stale.contains(entity))
+                    .chain(fresh)
+                    .filter(|(entity, position)| !world.is_disabled(*entity) && distance(center, *position) <= radius)
+                    .map(|(entity, _)| entity)
+                    .collect()
+                )) // Ok()
+            }) // during()
+        } // fn ..
+
+
+        pub fn within_aabb<C: Position>(&self, min: [f32; 3], max: [f32; 3]) -> Result<Vec<Entity>, EcsErr<B, F, P>> {
+            self.during("within_aabb", |world| {
+
+                let index          = world.spatial_index::<C>()?;
+                let (stale, fresh) = index.lent_positions(&world.lent_cells_of(world.component_bit_mask::<C>()?));
+
+                Ok(world.ordered_entities(index
+                    .within_cells(index.cell_of(min), index.cell_of(max), |_| true)
+                    .into_iter()
+                    .filter(|(entity, _)| !stale.contains(entity))
+                    .chain(fresh)
+                    .filter(|(entity, position)| !world.is_disabled(*entity) && (0..3).all(|axis| min[axis] <= position[axis] && position[axis] <= max[axis]))
+                    .map(|(entity, _)| entity)
+                    .collect()
+                )) // Ok()
+            }) // during()
+        } // fn ..
+
+
+        pub fn nearest<C: Position>(&self, point: [f32; 3]) -> Result<Option<Entity>, EcsErr<B, F, P>> {
+            self.during("nearest", |world| {
+
+                let index          = world.spatial_index::<C>()?;
+                let (stale, fresh) = index.lent_positions(&world.lent_cells_of(world.component_bit_mask::<C>()?));
+
+                Ok(fresh
+                    .into_iter()
+                    .filter(|(entity, _)| !world.is_disabled(*entity))
+                    .map(|(entity, position)| (distance(point, position), entity))
+                    .chain(index.nearest(point, |entity| stale.contains(&entity) || world.is_disabled(entity)))
+                    .min_by(closer)
+                    .map(|(_, entity)| entity)
+                ) // Ok()
+            }) // during()
+        } // fn ..
+
+
+        fn spatial_index<C: Position>(&self) -> Result<&SpatialIndex<C>, EcsErr<B, F, P>> {
+
+            let kind = ComponentType::of::<C>();
+            match self.indexes
+                .get(&kind)
+                .into_iter()
+                .flatten()
+                .find_map(|index| index.as_any().downcast_ref::<SpatialIndex<C>>()) {
+                    Some(index) => Ok(index),
+                    None        => Err(EcsErr::MissingIndex(kind, "position")),
+                } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+        (0..3)
+            .map(|axis| (a[axis] - b[axis]).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    } // fn ..
+
+
+    fn closer((distance, entity): &(f32, Entity), (other_distance, other): &(f32, Entity)) -> Ordering {
+        distance.total_cmp(other_distance).then(entity.cmp(other))
+    } // fn ..
+
+
+    fn volume(min: [i32; 3], max: [i32; 3]) -> usize {
+        (0..3)
+            .map(|axis| (max[axis] as i64 - min[axis] as i64 + 1i64).max(0i64) as usize)
+            .fold(1usize, |volume, length| volume.saturating_mul(length))
+    } // fn ..
@@ -0,0 +1,158 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt::Debug;
+    use std::hash::{Hash, Hasher};
+
+    use crate::worlds::World;
+    use crate::errors::ComponentType;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325u64;
+    const FNV_PRIME:        u64 = 0x00000100000001b3u64;
+
+
+    pub(crate) struct StateHasher(u64);
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct StateHash {
+        total:      u64,
+        entities:   u64,
+        components: Vec<(ComponentType, u64)>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Default for StateHasher {
+        fn default() -> Self { StateHasher(FNV_OFFSET_BASIS) }
+    } // impl ..
+
+
+    impl Hasher for StateHasher {
+        fn finish(&self) -> u64 { self.0 }
+
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+
+                self.0 ^= u64::from(*byte);
+                self.0  = self.0.wrapping_mul(FNV_PRIME);
+
+            } // for ..
+        } // fn ..
+
+
+        fn write_u16(&mut self, i: u16)     { self.write(&i.to_le_bytes()) }
+        fn write_u32(&mut self, i: u32)     { self.write(&i.to_le_bytes()) }
+        fn write_u64(&mut self, i: u64)     { self.write(&i.to_le_bytes()) }
+        fn write_u128(&mut self, i: u128)   { self.write(&i.to_le_bytes()) }
+        fn write_usize(&mut self, i: usize) { self.write_u64(i as u64) }
+        fn write_i16(&mut self, i: i16)     { self.write(&i.to_le_bytes()) }
+        fn write_i32(&mut self, i: i32)     { self.write(&i.to_le_bytes()) }
+        fn write_i64(&mut self, i: i64)     { self.write(&i.to_le_bytes()) }
+        fn write_i128(&mut self, i: i128)   { self.write(&i.to_le_bytes()) }
+        fn write_isize(&mut self, i: isize) { self.write_i64(i as i64) }
+
+    } // impl ..
+
+
+    impl StateHash {
+        pub fn total(&self)      -> u64                    { self.total }
+        pub fn entities(&self)   -> u64                    { self.entities }
+        pub fn components(&self) -> &[(ComponentType, u64)] { &self.components }
+
+
+        pub fn component(&self, kind: ComponentType) -> Option<u64> {
+            self.components
+                .iter()
+                .find(|(other, _)| *other == kind)
+                .map(|(_, hash)| *hash)
+        } // fn ..
+
+
+        pub fn diverging_components(&self, other: &StateHash) -> Vec<ComponentType> {
+            self.components
+                .iter()
+                .filter(|(kind, hash)| other.component(*kind) != Some(*hash))
+                .map(|(kind, _)| *kind)
+                .chain(other.components
+                    .iter()
+                    .filter(|(kind, _)| self.component(*kind).is_none())
+                    .map(|(kind, _)| *kind)
+                ).collect()
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn state_hash(&self) -> StateHash {
+
+            let mut entities = self.entities.keys().copied().collect::<Vec<Entity>>();
+            entities.sort_unstable();
+
+            let mut state = StateHasher::default();
+            state.write_u64(self.next_entity_id as u64);
+
+            for entity in entities.iter() {
+
+                state.write_u64(entity.0 as u64);
+                self.entities[entity].hash(&mut state);
+
+                match self.parents.get(entity) {
+                    Some(parent) => { state.write_u8(1u8); state.write_u64(parent.0 as u64); },
+                    None         => state.write_u8(0u8),
+                } // match ..
+
+            } // for ..
+
+            let entity_hash = state.finish();
+            let components  = self.components
+                .iter()
+                .enumerate()
+                .filter_map(|(index, info)| info.hash.map(|hash| (B::nth_bit(index as u8), info.kind, hash)))
+                .map(|(bit_mask, kind, hash)| {
+
+                    let mut state = StateHasher::default();
+                    let column    = self.component_columns.get(&bit_mask);
+
+                    for entity in entities.iter().filter(|entity| self.entities[entity].has_bits(bit_mask)) {
+
+                        state.write_u64(entity.0 as u64);
+                        state.write_u64(column
+                            .and_then(|column| column.get_cell(*entity))
+                            .and_then(hash)
+                            .unwrap_or_default()
+                        ); // write_u64()
+
+                    } // for ..
+
+                    (kind, state.finish())
+
+                }).collect::<Vec<(ComponentType, u64)>>();
+
+
+            let mut state = StateHasher::default();
+            state.write_u64(entity_hash);
+            components
+                .iter()
+                .for_each(|(_, hash)| state.write_u64(*hash));
+
+            StateHash {
+                total:    state.finish(),
+                entities: entity_hash,
+                components,
+            } // StateHash
+        } // fn ..
+    } // impl ..
@@ -0,0 +1,245 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::VecDeque;
+    use std::fmt::Debug;
+    use std::hash::Hash;
+    use std::mem;
+
+    use crate::worlds::World;
+    use crate::errors::EcsErr;
+    use crate::transactions::Change;
+    use crate::journals::JournalEntry;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone)]
+    pub struct History<B: BitField> {
+        undo_steps:    VecDeque<HistoryStep<B>>,
+        redo_steps:    Vec<HistoryStep<B>>,
+        memory_usage:  usize,
+        pending_usage: usize,
+        memory_budget: usize,
+    } // struct ..
+
+
+    #[derive(Clone)]
+    struct HistoryStep<B: BitField> {
+        name:    String,
+        changes: Vec<Change<B>>,
+        size:    usize,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> History<B> {
+        pub(crate) fn new(memory_budget: usize) -> Self {
+            History {
+                undo_steps:    VecDeque::default(),
+                redo_steps:    Vec::default(),
+                memory_usage:  0usize,
+                pending_usage: 0usize,
+                memory_budget,
+            } // History
+        } // fn ..
+
+
+        pub fn undo_steps(&self)    -> impl Iterator<Item = &str> { self.undo_steps.iter().map(|step| step.name.as_str()) }
+        pub fn redo_steps(&self)    -> impl Iterator<Item = &str> { self.redo_steps.iter().rev().map(|step| step.name.as_str()) }
+        pub fn memory_usage(&self)  -> usize                      { self.memory_usage + self.pending_usage }
+        pub fn memory_budget(&self) -> usize                      { self.memory_budget }
+
+
+        fn push_undo_step(&mut self, step: HistoryStep<B>) {
+
+            self.memory_usage += step.size;
+            self.undo_steps.push_back(step);
+            self.trim();
+
+        } // fn ..
+
+
+        fn trim(&mut self) {
+            while self.memory_usage + self.pending_usage > self.memory_budget {
+                match self.undo_steps.pop_front() {
+                    Some(step) => self.memory_usage -= step.size,
+                    None       => break,
+                } // match ..
+            } // while ..
+        } // fn ..
+
+
+        fn clear_redo_steps(&mut self) {
+            self.redo_steps
+                .drain(..)
+                .for_each(|step| self.memory_usage -= step.size);
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn history(&self) -> Option<&History<B>> { self.history.as_ref() }
+
+
+        pub fn set_history_budget(&mut self, memory_budget: usize) {
+            if let Some(history) = self.history.as_mut() {
+
+                history.memory_budget = memory_budget;
+                history.trim();
+
+            } // if ..
+
+            self.enforce_history_budget();
+
+        } // fn ..
+
+
+        pub fn commit_step<T: Into<String>>(&mut self, name: T) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("commit_step", |world| {
+
+                world.check_no_transaction()?;
+                world.push_history_step(name.into());
+                Ok(())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn undo(&mut self) -> Result<Option<String>, EcsErr<B, F, P>> {
+            self.during_mut("undo", |world| {
+
+                world.check_no_transaction()?;
+                Ok(world.undo_history_step())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn redo(&mut self) -> Result<Option<String>, EcsErr<B, F, P>> {
+            self.during_mut("redo", |world| {
+
+                world.check_no_transaction()?;
+                Ok(world.redo_history_step())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        fn check_no_transaction(&self) -> Result<(), EcsErr<B, F, P>> {
+            match self.atomic_depth {
+                0usize => Ok(()),
+                _      => Err(EcsErr::OpenTransaction),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn push_history_step(&mut self, name: String) {
+
+            self.settle_lent_cells();
+            let changes = match (self.history.is_some(), self.changes.as_mut()) {
+                (true, Some(changes)) if !changes.is_empty() => mem::take(changes),
+                _                                            => return,
+            }; // let ..
+
+            let size = changes.iter().map(|change| self.change_size(change)).sum();
+            self.record_entry(|_| JournalEntry::Commit(name.clone()));
+
+            if let Some(history) = self.history.as_mut() {
+
+                history.pending_usage = 0usize;
+                history.clear_redo_steps();
+                history.push_undo_step(HistoryStep { name, changes, size });
+
+            } // if ..
+        } // fn ..
+
+
+        pub(crate) fn undo_history_step(&mut self) -> Option<String> {
+
+            self.push_history_step(String::from("uncommitted"));
+
+            let step = self.history.as_mut()?.undo_steps.pop_back()?;
+            let name = step.name.clone();
+
+            self.revert_changes(&step.changes);
+            if let Some(history) = self.history.as_mut() { history.redo_steps.push(step); }
+            self.record_entry(|_| JournalEntry::Undo);
+
+            Some(name)
+
+        } // fn ..
+
+
+        pub(crate) fn redo_history_step(&mut self) -> Option<String> {
+
+            self.push_history_step(String::from("uncommitted"));
+
+            let step = self.history.as_mut()?.redo_steps.pop()?;
+            let name = step.name.clone();
+
+            step.changes
+                .iter()
+                .for_each(|change| self.apply_change(change, true));
+
+            if let Some(history) = self.history.as_mut() { history.undo_steps.push_back(step); }
+            self.record_entry(|_| JournalEntry::Redo);
+
+            Some(name)
+
+        } // fn ..
+
+
+        pub(crate) fn count_pending_changes(&mut self, changes: &[Change<B>], counted: bool) {
+
+            let size = changes.iter().map(|change| self.change_size(change)).sum::<usize>();
+            if let Some(history) = self.history.as_mut() {
+                match counted {
+                    true  => history.pending_usage += size,
+                    false => history.pending_usage -= size.min(history.pending_usage),
+                } // match ..
+            } // if ..
+        } // fn ..
+
+
+        pub(crate) fn enforce_history_budget(&mut self) {
+
+            if self.atomic_depth > 0usize { return }
+            if let Some(history) = self.history.as_mut() {
+
+                history.trim();
+                if history.pending_usage > history.memory_budget {
+
+                    history.clear_redo_steps();
+                    history.undo_steps.clear();
+                    history.memory_usage  = 0usize;
+                    history.pending_usage = 0usize;
+
+                    if let Some(changes) = self.changes.as_mut() { changes.clear(); }
+
+                } // if ..
+            } // if ..
+        } // fn ..
+
+
+        fn change_size(&self, change: &Change<B>) -> usize {
+
+            let component_size = |column_bit_mask: B| self.column_info(column_bit_mask)
+                .map(|info| info.size)
+                .unwrap_or_default();
+
+            mem::size_of::<Change<B>>() + match change {
+                Change::Cell(column_bit_mask, _, _, _)        => component_size(*column_bit_mask),
+                Change::Value(Some(column_bit_mask), _, _, _) => 2usize * component_size(*column_bit_mask),
+                _                                             => 0usize,
+            } // match ..
+        } // fn ..
+    } // impl ..
@@ -0,0 +1,210 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, HashSet};
+    use std::any::Any;
+    use std::fmt::Debug;
+    use std::hash::Hash;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    use crate::worlds::World;
+    use crate::errors::{EcsErr, ComponentType};
+    use crate::components::{Component, ComponentCell};
+    use crate::entities::Entity;
+    use crate::names::{self, Name};
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub(crate) trait ComponentIndex {
+        fn as_any(&self) -> &dyn Any;
+        fn unique(&self) -> bool;
+        fn insert(&mut self, entity: Entity, cell: &dyn ComponentCell) -> Option<Entity>;
+        fn remove(&mut self, entity: Entity);
+        fn entities_of_cell(&self, cell: &dyn ComponentCell) -> Vec<Entity>;
+    } // trait ..
+
+
+    pub(crate) struct KeyIndex<C: Component, K: Hash + Eq + Clone> {
+        key:      fn(&C) -> K,
+        unique:   bool,
+        keys:     HashMap<Entity, (K, usize)>,
+        entities: HashMap<K, Vec<Entity>>,
+        cells:    HashMap<usize, Vec<Entity>>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<C: Component, K: Hash + Eq + Clone> KeyIndex<C, K> {
+        pub(crate) fn new(key: fn(&C) -> K, unique: bool) -> Self {
+            KeyIndex {
+                key,
+                unique,
+                keys:     HashMap::default(),
+                entities: HashMap::default(),
+                cells:    HashMap::default(),
+            } // KeyIndex
+        } // fn ..
+
+
+        fn key_of(&self, cell: &dyn ComponentCell) -> Option<K> {
+            cell
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .and_then(|cell| cell.try_borrow().ok().map(|component| (self.key)(&component)))
+        } // fn ..
+    } // impl ..
+
+
+    impl<C: Component, K: Hash + Eq + Clone + 'static> ComponentIndex for KeyIndex<C, K> {
+        fn as_any(&self) -> &dyn Any { self }
+        fn unique(&self) -> bool     { self.unique }
+
+
+        fn insert(&mut self, entity: Entity, cell: &dyn ComponentCell) -> Option<Entity> {
+
+            let key      = self.key_of(cell)?;
+            let entities = self.entities.entry(key.clone()).or_default();
+            let conflict = entities.first().copied().filter(|_| self.unique);
+
+            entities.push(entity);
+            self.cells.entry(cell.cell_id()).or_default().push(entity);
+            self.keys.insert(entity, (key, cell.cell_id()));
+
+            conflict
+
+        } // fn ..
+
+
+        fn remove(&mut self, entity: Entity) {
+            if let Some((key, cell_id)) = self.keys.remove(&entity) {
+
+                if let Some(entities) = self.entities.get_mut(&key) {
+
+                    entities.retain(|other| *other != entity);
+                    if entities.is_empty() { self.entities.remove(&key); }
+
+                } // if ..
+
+                if let Some(entities) = self.cells.get_mut(&cell_id) {
+
+                    entities.retain(|other| *other != entity);
+                    if entities.is_empty() { self.cells.remove(&cell_id); }
+
+                } // if ..
+            } // if ..
+        } // fn ..
+
+
+        fn entities_of_cell(&self, cell: &dyn ComponentCell) -> Vec<Entity> {
+            self.cells
+                .get(&cell.cell_id())
+                .cloned()
+                .unwrap_or_default()
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn lookup<C: Component, K: Hash + Eq + Clone + 'static>(&self, key: &K) -> Result<impl Iterator<Item = Entity> + '_, EcsErr<B, F, P>> {
+            self.during("lookup", |world| {
+
+                let kind  = ComponentType::of::<C>();
+                let index = match world.indexes
+                    .get(&kind)
+                    .into_iter()
+                    .flatten()
+                    .find_map(|index| index.as_any().downcast_ref::<KeyIndex<C, K>>()) {
+                        Some(index) => index,
+                        None        => return Err(EcsErr::MissingIndex(kind, std::any::type_name::<K>())),
+                    }; // let ..
+
+                let lent_cells = world.lent_cells_of(world.component_bit_mask::<C>()?);
+                let stale      = lent_cells
+                    .iter()
+                    .flat_map(|cell| index.entities_of_cell(cell.as_ref()))
+                    .collect::<HashSet<Entity>>();
+                let fresh      = lent_cells
+                    .iter()
+                    .filter(|cell| index.key_of(cell.as_ref()).as_ref() == Some(key))
+                    .flat_map(|cell| index.entities_of_cell(cell.as_ref()))
+                    .collect::<Vec<Entity>>();
+
+                Ok(index.entities
+                    .get(key)
+                    .into_iter()
+                    .flatten()
+                    .copied()
+                    .filter(move |entity| !stale.contains(entity))
+                    .chain(fresh)
+                    .filter(|entity| !world.is_disabled(*entity))
+                ) // Ok()
+            }) // during()
+        } // fn ..
+
+
+        pub(crate) fn is_indexed(&self, column_bit_mask: Option<B>) -> bool {
+            !self.indexes.is_empty() && column_bit_mask
+                .and_then(|column_bit_mask| self.column_info(column_bit_mask))
+                .and_then(|info| self.indexes.get(&info.kind))
+                .is_some_and(|indexes| !indexes.is_empty())
+        } // fn ..
+
+
+        pub(crate) fn checking_indexes<R>(
+            &mut self,
+            f: impl FnOnce(&mut Self) -> Result<R, EcsErr<B, F, P>>,
+        ) -> Result<R, EcsErr<B, F, P>> {
+            match self.indexes.values().flatten().any(|index| index.unique()) {
+                true  => self.atomically(f),
+                false => f(self),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn reindex(&mut self, column_bit_mask: B, entity: Entity, report: bool) {
+
+            let kind = match self.column_info(column_bit_mask) {
+                Some(info) => info.kind,
+                None       => return,
+            }; // let ..
+
+            let cell = self.component_columns
+                .get(&column_bit_mask)
+                .and_then(|column| column.get_cell(entity));
+
+            for index in self.indexes.get_mut(&kind).into_iter().flatten() {
+
+                index.remove(entity);
+                if let Some(other) = cell.and_then(|cell| index.insert(entity, cell)) {
+                    if report && self.index_conflict.is_none() { self.index_conflict = Some(EcsErr::DuplicateIndexKey(kind, entity, other)); }
+                } // if ..
+
+            } // for ..
+
+            if kind == ComponentType::of::<Name>() { names::set_debug_name(self.id, entity, cell); }
+        } // fn ..
+
+
+        pub(crate) fn reindex_cell(&mut self, column_bit_mask: B, cell: &dyn ComponentCell, report: bool) {
+
+            let entities = self.column_info(column_bit_mask)
+                .and_then(|info| self.indexes.get(&info.kind))
+                .into_iter()
+                .flatten()
+                .flat_map(|index| index.entities_of_cell(cell))
+                .collect::<Vec<Entity>>();
+
+            for entity in entities { self.reindex(column_bit_mask, entity, report); }
+
+        } // fn ..
+    } // impl ..
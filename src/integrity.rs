@@ -0,0 +1,199 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt::{Debug, Display};
+    use std::fmt;
+    use std::hash::Hash;
+
+    use crate::worlds::World;
+    use crate::errors::ComponentType;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum IntegrityIssue<B: BitField> {
+        MissingComponentEntry(Entity, ComponentType),
+        MissingComponentBit(Entity, ComponentType),
+        DanglingComponent(Entity, ComponentType),
+        UnregisteredBits(Entity, B),
+        DanglingParent(Entity, Entity),
+    } // enum ..
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct IntegrityReport<B: BitField> {
+        issues: Vec<IntegrityIssue<B>>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> IntegrityReport<B> {
+        pub fn is_valid(&self) -> bool                 { self.issues.is_empty() }
+        pub fn issues(&self)   -> &[IntegrityIssue<B>] { &self.issues }
+    } // impl ..
+
+
+    impl<B: BitField> Display for IntegrityIssue<B> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", match self {
+                IntegrityIssue::MissingComponentEntry(e, c) => format!("The entity `{:?}` has the `{}` bit but no `{}` entry!", e, c, c),
+                IntegrityIssue::MissingComponentBit(e, c)   => format!("The entity `{:?}` has a `{}` entry but no `{}` bit!", e, c, c),
+                IntegrityIssue::DanglingComponent(e, c)     => format!("The `{}` column holds an entry for the unregistered entity `{:?}`!", c, e),
+                IntegrityIssue::UnregisteredBits(e, b)      => format!("The entity `{:?}` has the unregistered bits `{:x}`!", e, b),
+                IntegrityIssue::DanglingParent(e, p)        => format!("The entity `{:?}` is parented to the unregistered entity `{:?}`!", e, p),
+            }) // write()
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField> Display for IntegrityReport<B> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.issues
+                .iter()
+                .try_for_each(|issue| writeln!(f, "{}", issue))
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn validate(&self) -> IntegrityReport<B> {
+
+            let mut issues          = Vec::new();
+            let registered_bit_mask = self.registered_bit_mask();
+            let entities            = self.ordered_entities(self.entities.keys().copied().collect())
+                .into_iter()
+                .map(|entity| (entity, self.entities[&entity]))
+                .collect::<Vec<(Entity, B)>>();
+
+            for (index, info) in self.components.iter().enumerate() {
+
+                let bit_mask = B::nth_bit(index as u8);
+                let column   = self.component_columns.get(&bit_mask);
+
+                for (entity, entity_bit_mask) in entities.iter() {
+                    match (entity_bit_mask.has_bits(bit_mask), column.and_then(|column| column.get_cell(*entity)).is_some()) {
+                        (true, false) => issues.push(IntegrityIssue::MissingComponentEntry(*entity, info.kind)),
+                        (false, true) => issues.push(IntegrityIssue::MissingComponentBit(*entity, info.kind)),
+                        _             => (),
+                    } // match ..
+                } // for ..
+
+                if let Some(column) = column {
+                    self.ordered_entities(column.entities())
+                        .into_iter()
+                        .filter(|entity| !self.entities.contains_key(entity))
+                        .for_each(|entity| issues.push(IntegrityIssue::DanglingComponent(entity, info.kind)));
+                } // if ..
+            } // for ..
+
+
+            for (entity, entity_bit_mask) in entities.iter() {
+
+                let mut unregistered_bits = *entity_bit_mask;
+                unregistered_bits.unset_bits(registered_bit_mask);
+
+                if unregistered_bits != B::MIN { issues.push(IntegrityIssue::UnregisteredBits(*entity, unregistered_bits)); }
+
+            } // for ..
+
+
+            self.ordered_entities(self.parents.keys().copied().collect())
+                .into_iter()
+                .map(|entity| (entity, self.parents[&entity]))
+                .filter(|(entity, parent)| !self.entities.contains_key(entity) || !self.entities.contains_key(parent))
+                .for_each(|(entity, parent)| issues.push(IntegrityIssue::DanglingParent(entity, parent)));
+
+            IntegrityReport { issues }
+
+        } // fn ..
+
+
+        pub(crate) fn debug_validate(&self, operation: &'static str) {
+            if cfg!(debug_assertions) && self.debug_validation {
+
+                let report = self.validate();
+                assert!(report.is_valid(), "The operation `{}` broke the world integrity:\n{}", operation, report);
+
+            } // if ..
+        } // fn ..
+    } // impl ..
+
+
+//#############
+// T E S T S
+//#############
+
+    #[cfg(test)]
+    mod tests {
+
+        use crate::worlds::World;
+        use crate::errors::ComponentType;
+        use crate::components::Component;
+        use crate::entities::Entity;
+        use crate::integrity::IntegrityIssue;
+
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Health(i32);
+
+
+        impl Component for Health {}
+
+
+        fn family() -> (World<u16, u8, u8>, Entity, Entity) {
+
+            let mut world = World::<u16, u8, u8>::builder()
+                .with_component::<Health>()
+                .build()
+                .unwrap();
+
+            let parent = world.new_entity().with_component(Health(1)).unwrap().build();
+            let child  = world.new_entity().with_component(Health(2)).unwrap().build();
+            world.set_entity_parent(child, parent).unwrap();
+
+            assert!(world.validate().is_valid());
+            (world, parent, child)
+
+        } // fn ..
+
+
+        #[test]
+        fn validate_reports_each_corruption() {
+
+            let health = ComponentType::of::<Health>();
+
+            let (mut world, parent, _) = family();
+            world.component_columns.get_mut(&1u16).unwrap().remove_cell(parent);
+            assert_eq!(world.validate().issues(), &[IntegrityIssue::MissingComponentEntry(parent, health)]);
+
+            let (mut world, parent, _) = family();
+            world.entities.insert(parent, 0u16);
+            assert_eq!(world.validate().issues(), &[IntegrityIssue::MissingComponentBit(parent, health)]);
+
+            let (mut world, parent, child) = family();
+            world.entities.remove(&parent);
+            assert_eq!(world.validate().issues(), &[
+                IntegrityIssue::DanglingComponent(parent, health),
+                IntegrityIssue::DanglingParent(child, parent),
+            ]); // assert_eq!()
+
+            let (mut world, _, child) = family();
+            world.entities.insert(child, 1u16 | 1u16 << 12);
+            assert_eq!(world.validate().issues(), &[IntegrityIssue::UnregisteredBits(child, 1u16 << 12)]);
+
+            let (mut world, _, child) = family();
+            world.parents.insert(child, Entity(64));
+            assert_eq!(world.validate().issues(), &[IntegrityIssue::DanglingParent(child, Entity(64))]);
+
+        } // fn ..
+    } // mod ..
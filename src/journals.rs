@@ -0,0 +1,523 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt::{Debug, Display};
+    use std::fmt;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::hash::Hash;
+    use std::str::FromStr;
+
+    use crate::worlds::World;
+    use crate::errors::{EcsErr, ComponentType};
+    use crate::components::{Component, ComponentCell};
+    use crate::entities::{Entity, EntityId};
+    use crate::scenes::split_word;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait JournalComponent: Component {
+        fn to_journal(&self) -> String;
+        fn from_journal(value: &str) -> Option<Self>;
+    } // trait ..
+
+
+    pub struct Journal<B: BitField, P> {
+        entries:       Vec<JournalEntry<B, P>>,
+        clone_pointer: Option<fn(&P) -> P>,
+    } // struct ..
+
+
+    #[derive(Clone)]
+    pub(crate) enum JournalEntry<B: BitField, P> {
+        BitMask(Entity, Option<B>),
+        Insert(Entity, String, String),
+        Assign(Entity, String, String),
+        Remove(Entity, String),
+        Share(Entity, String, Entity),
+        SharePointer(Entity, String, P),
+        Parent(Entity, Option<Entity>),
+        NextEntityId(EntityId),
+        Pointer(P, String, String),
+        Unjournaled(String),
+        Commit(String),
+        Undo,
+        Redo,
+    } // enum ..
+
+
+    #[derive(Clone, Copy)]
+    pub(crate) struct JournalComponentLoader {
+        pub(crate) kind:  ComponentType,
+        pub(crate) write: fn(&dyn ComponentCell) -> Option<String>,
+        pub(crate) read:  fn(&str) -> Option<Box<dyn ComponentCell>>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl JournalComponentLoader {
+        pub(crate) fn new<C: JournalComponent>() -> Self {
+            JournalComponentLoader {
+                kind:  ComponentType::of::<C>(),
+                write: |cell| cell
+                    .as_any()
+                    .downcast_ref::<Rc<RefCell<C>>>()
+                    .and_then(|cell| cell.try_borrow().ok().map(|component| component.to_journal())),
+                read:  |value| C::from_journal(value).map(|component| Box::new(Rc::new(RefCell::new(component))) as Box<dyn ComponentCell>),
+            } // JournalComponentLoader
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, P> Default for Journal<B, P> {
+        fn default() -> Self {
+            Journal {
+                entries:       Vec::default(),
+                clone_pointer: None,
+            } // Journal
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, P> JournalEntry<B, P> {
+        fn is_history(&self) -> bool { matches!(self, JournalEntry::Commit(_) | JournalEntry::Undo | JournalEntry::Redo) }
+    } // impl ..
+
+
+    impl<B: BitField, P> Journal<B, P> {
+        pub(crate) fn new(clone_pointer: fn(&P) -> P) -> Self {
+            Journal {
+                entries:       Vec::default(),
+                clone_pointer: Some(clone_pointer),
+            } // Journal
+        } // fn ..
+
+
+        pub fn len(&self)      -> usize { self.entries.len() }
+        pub fn is_empty(&self) -> bool  { self.entries.is_empty() }
+
+        pub(crate) fn truncate(&mut self, len: usize) { self.entries.truncate(len) }
+
+    } // impl ..
+
+
+    impl<B: BitField + Display, P: Display> Display for JournalEntry<B, P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                JournalEntry::BitMask(e, Some(b))      => write!(f, "mask {} {}", e.0, b),
+                JournalEntry::BitMask(e, None)         => write!(f, "despawn {}", e.0),
+                JournalEntry::Insert(e, n, v)          => write!(f, "insert {} {} {}", e.0, n, v),
+                JournalEntry::Assign(e, n, v)          => write!(f, "assign {} {} {}", e.0, n, v),
+                JournalEntry::Remove(e, n)             => write!(f, "remove {} {}", e.0, n),
+                JournalEntry::Share(e, n, s)           => write!(f, "share {} {} {}", e.0, n, s.0),
+                JournalEntry::SharePointer(e, n, p)    => write!(f, "share_pointer {} {} {}", e.0, n, p),
+                JournalEntry::Parent(e, Some(p))       => write!(f, "parent {} {}", e.0, p.0),
+                JournalEntry::Parent(e, None)          => write!(f, "unparent {}", e.0),
+                JournalEntry::NextEntityId(i)          => write!(f, "next {}", i),
+                JournalEntry::Pointer(p, n, v)         => write!(f, "pointer {} {} {}", p, n, v),
+                JournalEntry::Unjournaled(n)           => write!(f, "unjournaled {}", n),
+                JournalEntry::Commit(n)                => write!(f, "commit {}", n),
+                JournalEntry::Undo                     => write!(f, "undo"),
+                JournalEntry::Redo                     => write!(f, "redo"),
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField + Display, P: Display> Display for Journal<B, P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.entries
+                .iter()
+                .try_for_each(|entry| writeln!(f, "{}", entry))
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn journal(&self) -> Option<&Journal<B, P>> { self.journal.as_ref() }
+
+
+        pub fn set_pointer_component<C: Component>(
+            &mut self,
+            id:        P,
+            component: C,
+        ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("set_pointer_component", |world| {
+
+                let cell = match world.component_pointers.get(&id) {
+                    None       => return Err(EcsErr::MissingComponentPtr(id)),
+                    Some(cell) => cell.clone_cell(),
+                }; // let ..
+
+                if cell.as_any().downcast_ref::<Rc<RefCell<C>>>().is_none() {
+                    return Err(EcsErr::FailedToDowncastPtr(id, ComponentType::of::<C>()))
+                } // if ..
+
+                let value = Box::new(Rc::new(RefCell::new(component))) as Box<dyn ComponentCell>;
+                world.assign_value(None, cell.as_ref(), value.as_ref());
+                world.record_entry(|world| match world.journal_value(ComponentType::of::<C>(), value.as_ref()) {
+                    Ok((name, value)) => JournalEntry::Pointer(id, name, value),
+                    Err(name)         => JournalEntry::Unjournaled(name),
+                }); // record_entry()
+
+                Ok(())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn load_journal(&self, source: &str) -> Result<Journal<B, P>, EcsErr<B, F, P>>
+        where B: FromStr, P: FromStr {
+
+            let mut journal = Journal::default();
+            for (index, line) in source.lines().enumerate() {
+
+                let invalid         = |message: String| EcsErr::InvalidJournal(index + 1usize, message);
+                let entity          = |value: &str| value.parse::<EntityId>().map(Entity).map_err(|_| invalid(format!("invalid entity `{}`", value)));
+                let (keyword, rest) = split_word(line.trim());
+                let (first, rest)   = split_word(rest);
+
+                let entry = match keyword {
+                    ""              => continue,
+                    "mask"          => JournalEntry::BitMask(entity(first)?, Some(rest.parse::<B>().map_err(|_| invalid(format!("invalid bit mask `{}`", rest)))?)),
+                    "despawn"       => JournalEntry::BitMask(entity(first)?, None),
+                    "insert"        => { let (name, value) = split_word(rest); JournalEntry::Insert(entity(first)?, name.to_string(), value.to_string()) },
+                    "assign"        => { let (name, value) = split_word(rest); JournalEntry::Assign(entity(first)?, name.to_string(), value.to_string()) },
+                    "remove"        => JournalEntry::Remove(entity(first)?, rest.to_string()),
+                    "share"         => { let (name, source) = split_word(rest); JournalEntry::Share(entity(first)?, name.to_string(), entity(source)?) },
+                    "share_pointer" => {
+
+                        let (name, id) = split_word(rest);
+                        let id         = id.parse::<P>().map_err(|_| invalid(format!("invalid component pointer `{}`", id)))?;
+                        JournalEntry::SharePointer(entity(first)?, name.to_string(), id)
+
+                    }, // => ..
+                    "parent"        => JournalEntry::Parent(entity(first)?, Some(entity(rest)?)),
+                    "unparent"      => JournalEntry::Parent(entity(first)?, None),
+                    "next"          => JournalEntry::NextEntityId(first.parse::<EntityId>().map_err(|_| invalid(format!("invalid entity id `{}`", first)))?),
+                    "pointer"       => {
+
+                        let (name, value) = split_word(rest);
+                        let id            = first.parse::<P>().map_err(|_| invalid(format!("invalid component pointer `{}`", first)))?;
+                        JournalEntry::Pointer(id, name.to_string(), value.to_string())
+
+                    }, // => ..
+                    "unjournaled"   => JournalEntry::Unjournaled(line.trim()["unjournaled".len()..].trim().to_string()),
+                    "commit"        => JournalEntry::Commit(line.trim()["commit".len()..].trim().to_string()),
+                    "undo"          => JournalEntry::Undo,
+                    "redo"          => JournalEntry::Redo,
+                    keyword         => return Err(invalid(format!("unknown keyword `{}`", keyword))),
+                }; // let ..
+
+                journal.entries.push(entry);
+
+            } // for ..
+
+            Ok(journal)
+
+        } // fn ..
+
+
+        pub fn replay_journal(&mut self, journal: &Journal<B, P>) -> Result<(), EcsErr<B, F, P>>
+        where P: Clone {
+
+            self.settle_lent_cells();
+
+            let checkpoint = self.checkpoint();
+            let result     = self.replay(journal).map_err(|err| self.named(err.during("replay_journal")));
+
+            if result.is_err() { self.rollback(checkpoint); }
+            self.index_conflict = None;
+
+            self.debug_validate("replay_journal");
+            result
+
+        } // fn ..
+
+
+        fn replay(&mut self, journal: &Journal<B, P>) -> Result<(), EcsErr<B, F, P>>
+        where P: Clone {
+
+            for (index, entry) in journal.entries.iter().enumerate() {
+                self.check_entry(entry).map_err(|message| EcsErr::InvalidJournal(index + 1usize, message))?;
+            } // for ..
+
+            let mut offset = 0usize;
+            for segment in journal.entries.split_inclusive(|entry| entry.is_history()) {
+
+                let (entries, history) = match segment.split_last() {
+                    Some((last, entries)) if last.is_history() => (entries, Some(last)),
+                    _                                          => (segment, None),
+                }; // let ..
+
+                for (index, entry) in entries.iter().enumerate() {
+                    self.replay_entry(entry).map_err(|message| EcsErr::InvalidJournal(offset + index + 1usize, message))?;
+                } // for ..
+
+                if let Some(err) = self.index_conflict.take() { return Err(err) }
+
+                let line = offset + segment.len();
+                match history {
+                    Some(JournalEntry::Commit(name))                                => self.push_history_step(name.clone()),
+                    Some(JournalEntry::Undo) if self.undo_history_step().is_none()  => return Err(EcsErr::InvalidJournal(line, String::from("there is no step to undo"))),
+                    Some(JournalEntry::Redo) if self.redo_history_step().is_none()  => return Err(EcsErr::InvalidJournal(line, String::from("there is no step to redo"))),
+                    _                                                               => (),
+                } // match ..
+
+                offset = line;
+
+            } // for ..
+
+            Ok(())
+
+        } // fn ..
+
+
+        fn check_entry(&self, entry: &JournalEntry<B, P>) -> Result<(), String> {
+            match entry {
+                JournalEntry::Insert(_, name, value)
+                | JournalEntry::Assign(_, name, value)    => self.read_journal_value(name, value).map(|_| ()),
+                JournalEntry::Remove(_, name)
+                | JournalEntry::Share(_, name, _)         => self.journal_column(name).map(|_| ()).ok_or_else(|| format!("unknown component `{}`", name)),
+                JournalEntry::SharePointer(_, name, id)   => match (self.journal_column(name), self.component_pointers.contains_key(id)) {
+                    (None, _)  => Err(format!("unknown component `{}`", name)),
+                    (_, false) => Err(format!("unknown component pointer `{:?}`", id)),
+                    _          => Ok(()),
+                }, // => ..
+                JournalEntry::Pointer(id, name, value)    => match (self.journal_components.get(name).and_then(|loader| (loader.read)(value)), self.component_pointers.contains_key(id)) {
+                    (None, _)  => Err(format!("invalid `{}` value `{}`", name, value)),
+                    (_, false) => Err(format!("unknown component pointer `{:?}`", id)),
+                    _          => Ok(()),
+                }, // => ..
+                JournalEntry::Unjournaled(name)           => Err(format!("the component `{}` is not journaled", name)),
+                JournalEntry::Undo | JournalEntry::Redo   => match self.history.is_some() {
+                    true  => Ok(()),
+                    false => Err(String::from("the world has no history to undo or redo")),
+                }, // => ..
+                _                                         => Ok(()),
+            } // match ..
+        } // fn ..
+
+
+        fn replay_entry(&mut self, entry: &JournalEntry<B, P>) -> Result<(), String>
+        where P: Clone {
+
+            match entry {
+                JournalEntry::BitMask(entity, bit_mask)      => self.write_bit_mask(*entity, *bit_mask),
+                JournalEntry::Insert(entity, name, value)    => {
+
+                    let (column_bit_mask, cell) = self.read_journal_value(name, value)?;
+                    self.write_cell(column_bit_mask, *entity, Some(cell));
+
+                }, // => ..
+                JournalEntry::Assign(entity, name, value)    => {
+
+                    let (column_bit_mask, value) = self.read_journal_value(name, value)?;
+                    let cell                     = self.journal_cell(column_bit_mask, *entity, name)?;
+
+                    self.assign_value(Some(column_bit_mask), cell.as_ref(), value.as_ref());
+                    self.record_entry(|_| entry.clone());
+
+                }, // => ..
+                JournalEntry::Remove(entity, name)           => match self.journal_column(name) {
+                    Some(column_bit_mask) => self.write_cell(column_bit_mask, *entity, None),
+                    None                  => return Err(format!("unknown component `{}`", name)),
+                }, // => ..
+                JournalEntry::Share(entity, name, source)    => {
+
+                    let column_bit_mask = self.journal_column(name).ok_or_else(|| format!("unknown component `{}`", name))?;
+                    let cell            = self.journal_cell(column_bit_mask, *source, name)?;
+                    self.write_cell(column_bit_mask, *entity, Some(cell));
+
+                }, // => ..
+                JournalEntry::SharePointer(entity, name, id) => {
+
+                    let column_bit_mask = self.journal_column(name).ok_or_else(|| format!("unknown component `{}`", name))?;
+                    let cell            = match self.component_pointers.get(id) {
+                        Some(cell) => cell.clone_cell(),
+                        None       => return Err(format!("unknown component pointer `{:?}`", id)),
+                    }; // let ..
+
+                    self.write_cell(column_bit_mask, *entity, Some(cell));
+
+                }, // => ..
+                JournalEntry::Parent(entity, parent)         => self.write_parent(*entity, *parent),
+                JournalEntry::NextEntityId(next_entity_id)   => self.write_next_entity_id(*next_entity_id),
+                JournalEntry::Pointer(id, name, value)       => {
+
+                    let value = match self.journal_components.get(name).and_then(|loader| (loader.read)(value)) {
+                        Some(value) => value,
+                        None        => return Err(format!("invalid `{}` value `{}`", name, value)),
+                    }; // let ..
+
+                    let cell = match self.component_pointers.get(id) {
+                        Some(cell) => cell.clone_cell(),
+                        None       => return Err(format!("unknown component pointer `{:?}`", id)),
+                    }; // let ..
+
+                    self.assign_value(None, cell.as_ref(), value.as_ref());
+                    self.record_entry(|_| entry.clone());
+
+                }, // => ..
+                JournalEntry::Unjournaled(name)              => return Err(format!("the component `{}` is not journaled", name)),
+                JournalEntry::Commit(_)
+                | JournalEntry::Undo
+                | JournalEntry::Redo                         => (),
+            } // match ..
+
+            Ok(())
+
+        } // fn ..
+
+
+        fn journal_cell(&self, column_bit_mask: B, entity: Entity, name: &str) -> Result<Box<dyn ComponentCell>, String> {
+            match self.component_columns
+                .get(&column_bit_mask)
+                .and_then(|component_column| component_column.get_cell(entity)) {
+                    Some(cell) => Ok(cell.clone_cell()),
+                    None       => Err(format!("the entity `{:?}` has no `{}` component", entity, name)),
+                } // match ..
+        } // fn ..
+
+
+        pub(crate) fn record_entry(&mut self, entry: impl FnOnce(&Self) -> JournalEntry<B, P>) {
+            if self.journal.is_some() {
+
+                let entry = entry(self);
+                if let Some(journal) = self.journal.as_mut() { journal.entries.push(entry); }
+
+            } // if ..
+        } // fn ..
+
+
+        pub(crate) fn journal_value(&self, kind: ComponentType, cell: &dyn ComponentCell) -> Result<(String, String), String> {
+            self.journal_components
+                .iter()
+                .find(|(_, loader)| loader.kind == kind)
+                .and_then(|(name, loader)| (loader.write)(cell).map(|value| (name.clone(), value)))
+                .ok_or_else(|| kind.name().to_string())
+        } // fn ..
+
+
+        pub(crate) fn journal_entry_of_cell(&self, column_bit_mask: B, entity: Entity, cell: Option<&dyn ComponentCell>) -> JournalEntry<B, P> {
+
+            let kind = match self.column_info(column_bit_mask) {
+                Some(info) => info.kind,
+                None       => return JournalEntry::Unjournaled(format!("{:x}", column_bit_mask)),
+            }; // let ..
+
+            let name = || self.journal_components
+                .iter()
+                .find(|(_, loader)| loader.kind == kind)
+                .map(|(name, _)| name.clone())
+                .unwrap_or_else(|| kind.name().to_string());
+
+            match cell {
+                Some(cell) => match self.is_shared_cell(cell).then(|| self.shared_cell_source(column_bit_mask, entity, cell)).flatten() {
+                    Some(Ok(source)) => JournalEntry::Share(entity, name(), source),
+                    Some(Err(id))    => JournalEntry::SharePointer(entity, name(), id),
+                    None             => match self.journal_value(kind, cell) {
+                        Ok((name, value)) => JournalEntry::Insert(entity, name, value),
+                        Err(name)         => JournalEntry::Unjournaled(name),
+                    }, // => ..
+                }, // => ..
+                None       => JournalEntry::Remove(entity, name()),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn journal_entry_of_value(&self, column_bit_mask: B, entity: Entity, cell: &dyn ComponentCell) -> JournalEntry<B, P> {
+
+            let kind = match self.column_info(column_bit_mask) {
+                Some(info) => info.kind,
+                None       => return JournalEntry::Unjournaled(format!("{:x}", column_bit_mask)),
+            }; // let ..
+
+            match self.journal_value(kind, cell) {
+                Ok((name, value)) => JournalEntry::Assign(entity, name, value),
+                Err(name)         => JournalEntry::Unjournaled(name),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn journal_entry_of_pointer(&self, cell: &dyn ComponentCell) -> JournalEntry<B, P> {
+
+            let id = match self.component_pointers
+                .iter()
+                .find(|(_, pointer)| pointer.cell_id() == cell.cell_id()) {
+                    Some((id, _)) => id,
+                    None          => return JournalEntry::Unjournaled(String::from("pointer")),
+                }; // let ..
+
+            let clone_pointer = self.journal.as_ref().and_then(|journal| journal.clone_pointer);
+            let value         = self.journal_components
+                .iter()
+                .find_map(|(name, loader)| (loader.write)(cell).map(|value| (name.clone(), value)));
+
+            match (clone_pointer, value) {
+                (Some(clone_pointer), Some((name, value))) => JournalEntry::Pointer(clone_pointer(id), name, value),
+                _                                          => JournalEntry::Unjournaled(format!("pointer {:?}", id)),
+            } // match ..
+        } // fn ..
+
+
+        fn is_shared_cell(&self, cell: &dyn ComponentCell) -> bool {
+            self.cell_holders.contains_key(&cell.cell_id()) || self.component_pointers
+                .values()
+                .any(|pointer| pointer.cell_id() == cell.cell_id())
+        } // fn ..
+
+
+        fn shared_cell_source(&self, column_bit_mask: B, entity: Entity, cell: &dyn ComponentCell) -> Option<Result<Entity, P>> {
+
+            let pointer = self.journal
+                .as_ref()
+                .and_then(|journal| journal.clone_pointer)
+                .and_then(|clone_pointer| self.component_pointers
+                    .iter()
+                    .find(|(_, pointer)| pointer.cell_id() == cell.cell_id())
+                    .map(|(id, _)| clone_pointer(id))
+                ); // let ..
+
+            if let Some(id) = pointer { return Some(Err(id)) }
+
+            let component_column = self.component_columns.get(&column_bit_mask)?;
+            component_column
+                .entities()
+                .into_iter()
+                .filter(|other| *other != entity && component_column.get_cell(*other).is_some_and(|other| other.cell_id() == cell.cell_id()))
+                .min()
+                .map(Ok)
+
+        } // fn ..
+
+
+        fn journal_column(&self, name: &str) -> Option<B> {
+            self.components
+                .iter()
+                .enumerate()
+                .find(|(_, info)| info.kind.name() == name || self.journal_components
+                    .get(name)
+                    .is_some_and(|loader| loader.kind == info.kind)
+                ).map(|(index, _)| B::nth_bit(index as u8))
+        } // fn ..
+
+
+        fn read_journal_value(&self, name: &str, value: &str) -> Result<(B, Box<dyn ComponentCell>), String> {
+
+            let column_bit_mask = self.journal_column(name).ok_or_else(|| format!("unknown component `{}`", name))?;
+            match self.journal_components.get(name).and_then(|loader| (loader.read)(value)) {
+                Some(cell) => Ok((column_bit_mask, cell)),
+                None       => Err(format!("invalid `{}` value `{}`", name, value)),
+            } // match ..
+        } // fn ..
+    } // impl ..
@@ -3,10 +3,34 @@
 //###############
 
     pub(crate) mod worlds;
+    pub(crate) mod errors;
     pub(crate) mod queries;
     pub(crate) mod entities;
     pub(crate) mod components;
+    pub(crate) mod prefabs;
+    pub(crate) mod scenes;
+    pub(crate) mod integrity;
+    pub(crate) mod transactions;
+    pub(crate) mod history;
+    pub(crate) mod journals;
+    pub(crate) mod hashes;
+    pub(crate) mod patches;
+    pub(crate) mod snapshots;
+    pub(crate) mod indexes;
+    pub(crate) mod grids;
+    pub(crate) mod names;
 
     pub use worlds::{World, WorldBuilder};
+    pub use errors::{EcsErr, EcsErrKind, ComponentType, BuildDiagnostic};
     pub use entities::Entity;
-    pub use components::Component;
+    pub use components::{Component, ComponentDescription, Reflect, HashComponent};
+    pub use queries::QueryState;
+    pub use prefabs::Prefab;
+    pub use scenes::{Scene, SceneComponent};
+    pub use integrity::{IntegrityIssue, IntegrityReport};
+    pub use history::History;
+    pub use journals::{Journal, JournalComponent};
+    pub use hashes::StateHash;
+    pub use patches::WorldPatch;
+    pub use grids::Position;
+    pub use names::{Name, EntityDisplay};
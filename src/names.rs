@@ -0,0 +1,159 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::HashMap;
+    use std::fmt::{self, Debug, Display};
+    use std::hash::{Hash, Hasher};
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    use crate::worlds::World;
+    use crate::errors::EcsErr;
+    use crate::components::{Component, ComponentCell, HashComponent};
+    use crate::entities::Entity;
+    use crate::scenes::SceneComponent;
+    use crate::journals::JournalComponent;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Name(pub String);
+
+
+    thread_local! {
+        static DEBUG_NAMES: RefCell<HashMap<Entity, HashMap<usize, String>>> = RefCell::default();
+    } // thread_local!
+
+
+    pub struct EntityDisplay<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        entity: Entity,
+        world:  &'world World<B, F, P>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Name {}
+
+
+    impl HashComponent for Name {
+        fn hash_component<H: Hasher>(&self, state: &mut H) { self.0.hash(state) }
+    } // impl ..
+
+
+    impl SceneComponent for Name {
+        fn from_scene(value: &str) -> Option<Self> { Some(Name(value.to_string())) }
+    } // impl ..
+
+
+    impl JournalComponent for Name {
+        fn to_journal(&self)         -> String       { self.0.clone() }
+        fn from_journal(value: &str) -> Option<Self> { Some(Name(value.to_string())) }
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Display for EntityDisplay<'_, B, F, P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", named_entity(self.entity, self.world.debug_name(self.entity).as_deref()))
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn entity_by_name(&self, name: &str) -> Result<Option<Entity>, EcsErr<B, F, P>> {
+            Ok(self.lookup::<Name, String>(&name.to_string())?.min())
+        } // fn ..
+
+
+        pub fn entity_name(&self, entity: Entity) -> Result<Option<String>, EcsErr<B, F, P>> {
+            self.during("entity_name", |world| {
+
+                world.get_entity_bit_mask(entity)?;
+                Ok(world
+                    .get_component_column::<Name>()?
+                    .get(&entity)
+                    .map(|name| name.borrow().0.clone())
+                ) // Ok()
+            }) // during()
+        } // fn ..
+
+
+        pub fn display(&self, entity: Entity) -> EntityDisplay<'_, B, F, P> { EntityDisplay { entity, world: self } }
+
+
+        pub(crate) fn debug_name(&self, entity: Entity) -> Option<String> {
+            self.get_component_column::<Name>()
+                .ok()
+                .and_then(|names| names.get(&entity))
+                .and_then(|name| name.try_borrow().ok().map(|name| name.0.clone()))
+        } // fn ..
+
+
+        pub(crate) fn named(&self, err: EcsErr<B, F, P>) -> EcsErr<B, F, P> {
+
+            let names = err
+                .entities()
+                .into_iter()
+                .filter_map(|entity| self.debug_name(entity).map(|name| (entity, name)))
+                .collect::<Vec<(Entity, String)>>();
+
+            err.named(names)
+
+        } // fn ..
+    } // impl ..
+
+
+    pub(crate) fn set_debug_name(world: usize, entity: Entity, cell: Option<&dyn ComponentCell>) {
+
+        let name = cell
+            .and_then(|cell| cell.as_any().downcast_ref::<Rc<RefCell<Name>>>())
+            .and_then(|name| name.try_borrow().ok().map(|name| name.0.clone()));
+
+        DEBUG_NAMES.with_borrow_mut(|names| match name {
+            Some(name) => { names.entry(entity).or_default().insert(world, name); },
+            None       => if let Some(worlds) = names.get_mut(&entity) {
+
+                worlds.remove(&world);
+                if worlds.is_empty() { names.remove(&entity); }
+
+            }, // => ..
+        }) // with_borrow_mut()
+    } // fn ..
+
+
+    pub(crate) fn forget_debug_names(world: usize) {
+        DEBUG_NAMES.with_borrow_mut(|names| names.retain(|_, worlds| {
+
+            worlds.remove(&world);
+            !worlds.is_empty()
+
+        })) // with_borrow_mut()
+    } // fn ..
+
+
+    pub(crate) fn debug_name(entity: Entity) -> Option<String> {
+        DEBUG_NAMES.with_borrow(|names| {
+
+            let mut worlds = names.get(&entity)?.values();
+            let name       = worlds.next()?;
+
+            worlds.all(|other| other == name).then(|| name.clone())
+
+        }) // with_borrow()
+    } // fn ..
+
+
+    pub(crate) fn named_entity(entity: Entity, name: Option<&str>) -> String {
+        match name {
+            Some(name) => format!("Entity({}, {:?})", entity.0, name),
+            None       => format!("Entity({})", entity.0),
+        } // match ..
+    } // fn ..
@@ -0,0 +1,292 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, HashSet};
+    use std::fmt::Debug;
+    use std::hash::Hash;
+
+    use crate::worlds::World;
+    use crate::errors::{EcsErr, ComponentType};
+    use crate::components::ComponentCell;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub struct WorldPatch<B: BitField> {
+        spawned: Vec<Entity>,
+        deleted: Vec<Entity>,
+        added:   Vec<PatchComponent>,
+        removed: Vec<(Entity, ComponentType)>,
+        changed: Vec<PatchComponent>,
+        flags:   Vec<(Entity, B, B)>,
+        parents: Vec<(Entity, Option<Entity>)>,
+    } // struct ..
+
+
+    struct PatchComponent {
+        entity: Entity,
+        kind:   ComponentType,
+        cell:   Box<dyn ComponentCell>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> WorldPatch<B> {
+        pub fn spawned(&self) -> &[Entity]                   { &self.spawned }
+        pub fn deleted(&self) -> &[Entity]                   { &self.deleted }
+        pub fn removed(&self) -> &[(Entity, ComponentType)]  { &self.removed }
+        pub fn flags(&self)   -> &[(Entity, B, B)]           { &self.flags }
+        pub fn parents(&self) -> &[(Entity, Option<Entity>)] { &self.parents }
+
+
+        pub fn added(&self) -> impl Iterator<Item = (Entity, ComponentType)> + '_ {
+            self.added
+                .iter()
+                .map(|component| (component.entity, component.kind))
+        } // fn ..
+
+
+        pub fn changed(&self) -> impl Iterator<Item = (Entity, ComponentType)> + '_ {
+            self.changed
+                .iter()
+                .map(|component| (component.entity, component.kind))
+        } // fn ..
+
+
+        pub fn is_empty(&self) -> bool {
+            self.spawned.is_empty()
+                && self.deleted.is_empty()
+                && self.added.is_empty()
+                && self.removed.is_empty()
+                && self.changed.is_empty()
+                && self.flags.is_empty()
+                && self.parents.is_empty()
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn diff(&self, other: &World<B, F, P>) -> WorldPatch<B> {
+
+            let entities  = other.ordered_entities(other.entities.keys().copied().collect());
+            let mut patch = WorldPatch {
+                spawned: entities
+                    .iter()
+                    .filter(|entity| !self.entities.contains_key(entity))
+                    .copied()
+                    .collect(),
+                deleted: self.ordered_entities(self.entities
+                    .keys()
+                    .filter(|entity| !other.entities.contains_key(entity))
+                    .copied()
+                    .collect()
+                ), // ordered_entities()
+                added:   Vec::default(),
+                removed: Vec::default(),
+                changed: Vec::default(),
+                flags:   Vec::default(),
+                parents: Vec::default(),
+            }; // let ..
+
+
+            let component_bit_mask = self.all_components_bit_mask();
+            for entity in entities.iter() {
+
+                let bit_mask       = self.entities.get(entity).copied().unwrap_or(B::MIN);
+                let other_bit_mask = other.entities[entity];
+
+                for info in self.components.iter() {
+
+                    let column_bit_mask       = self.column_bit_mask(info.kind);
+                    let other_column_bit_mask = other.column_bit_mask(info.kind);
+                    let cell                  = column_bit_mask
+                        .filter(|column_bit_mask| bit_mask.has_bits(*column_bit_mask))
+                        .and_then(|column_bit_mask| self.component_columns.get(&column_bit_mask))
+                        .and_then(|column| column.get_cell(*entity));
+                    let other_cell            = other_column_bit_mask
+                        .filter(|column_bit_mask| other_bit_mask.has_bits(*column_bit_mask))
+                        .and_then(|column_bit_mask| other.component_columns.get(&column_bit_mask))
+                        .and_then(|column| column.get_cell(*entity));
+
+                    match (cell, other_cell) {
+                        (None, Some(other_cell))       => patch.added.push(PatchComponent { entity: *entity, kind: info.kind, cell: other_cell.clone_value() }),
+                        (Some(_), None)                => patch.removed.push((*entity, info.kind)),
+                        (Some(cell), Some(other_cell)) => if info.eq.and_then(|eq| eq(cell, other_cell)) == Some(false) {
+                            patch.changed.push(PatchComponent { entity: *entity, kind: info.kind, cell: other_cell.clone_value() })
+                        }, // => ..
+                        (None, None)                   => (),
+                    } // match ..
+                } // for ..
+
+                let mut flag_bit_mask = bit_mask;
+                flag_bit_mask.unset_bits(component_bit_mask);
+
+                let mut other_flag_bit_mask = other_bit_mask;
+                other_flag_bit_mask.unset_bits(other.all_components_bit_mask());
+
+                if flag_bit_mask != other_flag_bit_mask { patch.flags.push((*entity, flag_bit_mask, other_flag_bit_mask)); }
+
+                let parent = other.get_entity_parent(*entity);
+                if self.get_entity_parent(*entity) != parent { patch.parents.push((*entity, parent)); }
+
+            } // for ..
+
+            patch
+
+        } // fn ..
+
+
+        pub fn apply_patch(&mut self, patch: &WorldPatch<B>) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("apply_patch", |world| {
+
+                let missing_entities = patch.deleted
+                    .iter()
+                    .chain(patch.removed.iter().map(|(entity, _)| entity))
+                    .chain(patch.changed.iter().map(|component| &component.entity))
+                    .chain(patch.flags.iter().map(|(entity, _, _)| entity))
+                    .chain(patch.parents.iter().map(|(entity, _)| entity))
+                    .chain(patch.added.iter().map(|component| &component.entity))
+                    .filter(|entity| !world.entities.contains_key(entity) && !patch.spawned.contains(entity))
+                    .copied()
+                    .collect::<Vec<Entity>>();
+
+                if !missing_entities.is_empty() { return Err(EcsErr::MissingEntityGroup(missing_entities)) }
+
+                if let Some(entity) = patch.spawned
+                    .iter()
+                    .find(|entity| world.entities.contains_key(entity) && !patch.deleted.contains(entity)) { return Err(EcsErr::ExistingEntity(*entity)) }
+
+                if let Some(kind) = patch.added
+                    .iter()
+                    .chain(patch.changed.iter())
+                    .map(|component| component.kind)
+                    .chain(patch.removed.iter().map(|(_, kind)| *kind))
+                    .find(|kind| world.column_bit_mask(*kind).is_none()) { return Err(EcsErr::MissingComponent(kind)) }
+
+                if let Some(component) = patch.changed
+                    .iter()
+                    .find(|component| world.column_bit_mask(component.kind)
+                        .and_then(|column_bit_mask| world.component_columns.get(&column_bit_mask))
+                        .and_then(|column| column.get_cell(component.entity))
+                        .is_none()
+                    ) { return Err(EcsErr::MissingComponentToEntity(component.kind, component.entity)) }
+
+
+                for entity in patch.deleted.iter() { world.despawn_entity(*entity); }
+                for entity in patch.spawned.iter() {
+
+                    world.write_bit_mask(*entity, Some(B::MIN));
+                    if entity.0 >= world.next_entity_id { world.write_next_entity_id(entity.0 + 1usize); }
+
+                } // for ..
+
+                for (entity, kind) in patch.removed.iter() {
+                    if let Some(column_bit_mask) = world.column_bit_mask(*kind) {
+
+                        let mut entity_bit_mask = world.get_entity_bit_mask(*entity)?;
+                        entity_bit_mask.unset_bits(column_bit_mask);
+
+                        world.write_cell(column_bit_mask, *entity, None);
+                        world.write_bit_mask(*entity, Some(entity_bit_mask));
+
+                    } // if ..
+                } // for ..
+
+                for component in patch.added.iter() {
+                    if let Some(column_bit_mask) = world.column_bit_mask(component.kind) {
+
+                        let mut entity_bit_mask = world.get_entity_bit_mask(component.entity)?;
+                        entity_bit_mask.set_bits(column_bit_mask);
+
+                        world.write_cell(column_bit_mask, component.entity, Some(component.cell.clone_value()));
+                        world.write_bit_mask(component.entity, Some(entity_bit_mask));
+
+                    } // if ..
+                } // for ..
+
+                let shared_cells = world.shared_cell_ids(patch.changed
+                    .iter()
+                    .filter_map(|component| world.column_bit_mask(component.kind))
+                    .collect()
+                ); // let ..
+
+                for component in patch.changed.iter() {
+                    if let Some(column_bit_mask) = world.column_bit_mask(component.kind) {
+                        world.assign_entity_value(column_bit_mask, component.entity, component.cell.as_ref(), &shared_cells);
+                    } // if ..
+                } // for ..
+
+                let component_bit_mask = world.all_components_bit_mask();
+                for (entity, _, flag_bit_mask) in patch.flags.iter() {
+                    world.write_bit_mask(*entity, Some((world.get_entity_bit_mask(*entity)? & component_bit_mask) | *flag_bit_mask));
+                } // for ..
+
+                for (entity, parent) in patch.parents.iter() { world.write_parent(*entity, *parent); }
+
+                Ok(())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        fn shared_cell_ids(&self, column_bit_masks: HashSet<B>) -> HashSet<usize> {
+
+            let mut counts = HashMap::<usize, usize>::new();
+            let cell_ids   = self.component_pointers
+                .values()
+                .map(|pointer| pointer.cell_id())
+                .chain(column_bit_masks
+                    .iter()
+                    .filter_map(|column_bit_mask| self.component_columns.get(column_bit_mask))
+                    .flat_map(|column| column
+                        .entities()
+                        .into_iter()
+                        .filter_map(|entity| column.get_cell(entity).map(|cell| cell.cell_id()))
+                    ) // flat_map()
+                ); // chain()
+
+            cell_ids.for_each(|cell_id| *counts.entry(cell_id).or_default() += 1usize);
+            counts
+                .into_iter()
+                .filter(|(_, count)| *count > 1usize)
+                .map(|(cell_id, _)| cell_id)
+                .collect()
+
+        } // fn ..
+
+
+        fn assign_entity_value(
+            &mut self,
+            column_bit_mask: B,
+            entity:          Entity,
+            value:           &dyn ComponentCell,
+            shared_cells:    &HashSet<usize>,
+        ) {
+
+            let cell = match self.component_columns
+                .get(&column_bit_mask)
+                .and_then(|column| column.get_cell(entity)) {
+                    Some(cell) => cell.clone_cell(),
+                    None       => return,
+                }; // let ..
+
+            match shared_cells.contains(&cell.cell_id()) {
+                true  => self.write_cell(column_bit_mask, entity, Some(value.clone_value())),
+                false => {
+
+                    self.assign_value(Some(column_bit_mask), cell.as_ref(), value);
+                    self.record_entry(|world| world.journal_entry_of_value(column_bit_mask, entity, cell.as_ref()));
+
+                }, // => ..
+            } // match ..
+        } // fn ..
+    } // impl ..
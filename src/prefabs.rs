@@ -0,0 +1,139 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt::Debug;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::hash::Hash;
+
+    use crate::worlds::World;
+    use crate::errors::EcsErr;
+    use crate::components::Component;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub struct Prefab<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        components: Vec<Box<dyn PrefabComponent<B, F, P>>>,
+        flags:      Vec<(F, Option<B>)>,
+        children:   Vec<Prefab<B, F, P>>,
+    } // struct ..
+
+
+    pub(crate) trait PrefabComponent<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        fn add_to_entity_builder(
+            &self,
+            world:           &mut World<B, F, P>,
+            entity:          Entity,
+            entity_bit_mask: &mut B,
+        ) -> Result<(), EcsErr<B, F, P>>;
+    } // trait ..
+
+
+    struct UniquePrefabComponent<C: Component>(C);
+    struct SharedPrefabComponent<C: Component>(Rc<RefCell<C>>);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug, C: Component> PrefabComponent<B, F, P> for UniquePrefabComponent<C> {
+        fn add_to_entity_builder(
+            &self,
+            world:           &mut World<B, F, P>,
+            entity:          Entity,
+            entity_bit_mask: &mut B,
+        ) -> Result<(), EcsErr<B, F, P>> { world.add_component_to_entity_builder(self.0.clone(), entity, entity_bit_mask) }
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug, C: Component> PrefabComponent<B, F, P> for SharedPrefabComponent<C> {
+        fn add_to_entity_builder(
+            &self,
+            world:           &mut World<B, F, P>,
+            entity:          Entity,
+            entity_bit_mask: &mut B,
+        ) -> Result<(), EcsErr<B, F, P>> { world.add_shared_component_to_entity_builder(&self.0, entity, entity_bit_mask) }
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Default for Prefab<B, F, P> {
+        fn default() -> Self { Prefab::new() }
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Prefab<B, F, P> {
+        pub fn new() -> Self {
+            Prefab {
+                components: Vec::default(),
+                flags:      Vec::default(),
+                children:   Vec::default(),
+            } // Prefab
+        } // fn ..
+
+
+        pub fn with_component<C: Component>(mut self, component: C) -> Self {
+
+            self.components.push(Box::new(UniquePrefabComponent(component)));
+            self
+
+        } // fn ..
+
+
+        pub fn with_shared_component<C: Component>(mut self, component: &Rc<RefCell<C>>) -> Self {
+
+            self.components.push(Box::new(SharedPrefabComponent(component.clone())));
+            self
+
+        } // fn ..
+
+
+        pub fn with_flag<T: Into<F>>(mut self, flag: T, variant: Option<B>) -> Self {
+
+            self.flags.push((flag.into(), variant));
+            self
+
+        } // fn ..
+
+
+        pub fn with_child(mut self, child: Prefab<B, F, P>) -> Self {
+
+            self.children.push(child);
+            self
+
+        } // fn ..
+
+
+        pub(crate) fn add_to_entity_builder(
+            &self,
+            world:           &mut World<B, F, P>,
+            entity:          Entity,
+            entity_bit_mask: &mut B,
+        ) -> Result<(), EcsErr<B, F, P>> {
+
+            for component in self.components.iter() {
+                component.add_to_entity_builder(world, entity, entity_bit_mask)?;
+            } // for ..
+
+            for (flag, variant) in self.flags.iter() {
+                world.add_flag_to_entity_builder(*flag, *variant, entity_bit_mask)?;
+            } // for ..
+
+            for child in self.children.iter() {
+
+                let child = world.new_entity().with_prefab(child)?.build_child();
+                world.link_entity_parent(child, entity);
+
+            } // for ..
+
+            Ok(())
+
+        } // fn ..
+    } // impl ..
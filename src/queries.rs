@@ -4,10 +4,16 @@
 
     use std::fmt::Debug;
     use std::rc::Rc;
-    use std::cell::RefCell;
+    use std::cell::{RefCell, Ref, RefMut};
     use std::hash::Hash;
+    use std::collections::{btree_set, BTreeSet, HashMap, HashSet};
+    use std::iter::Copied;
+    use std::marker::PhantomData;
+    use std::sync::Mutex;
+    use std::thread;
 
-    use crate::worlds::{World, EcsErr};
+    use crate::worlds::World;
+    use crate::errors::{EcsErr, ComponentType};
     use crate::components::Component;
     use crate::entities::Entity;
 
@@ -18,15 +24,75 @@
 // D E F I N I T I O N S
 //#######################
 
+    const SIGNATURE_LOG_MIN_LEN: usize = 1024usize;
+    type QueryFilter<B, F, P>            = Rc<dyn Fn(&World<B, F, P>, Entity) -> bool>;
+    type ComponentCell<C>                = Rc<RefCell<C>>;
+    type ComponentColumn<C>              = HashMap<Entity, ComponentCell<C>>;
+    type BorrowedRef<'query, B, F, P, C> = Result<ComponentsRef<'query, B, F, P, C>, EcsErr<B, F, P>>;
+    type BorrowedMut<'query, B, F, P, C> = Result<ComponentsMut<'query, B, F, P, C>, EcsErr<B, F, P>>;
+
+
     pub struct Query<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        entities: Vec<Entity>,
-        world:    &'world World<B, F, P>,
+        entities:         Option<Vec<Entity>>,
+        bit_mask:         B,
+        include_disabled: bool,
+        filters:          Vec<QueryFilter<B, F, P>>,
+        access:           QueryAccess<B, F, P>,
+        world:            &'world World<B, F, P>,
+    } // struct ..
+
+
+    pub struct ComponentsRef<'query, B: BitField, F: BitField, P: Hash + Eq + Debug, C: Component> {
+        query:  &'query Query<'query, B, F, P>,
+        column: &'query ComponentColumn<C>,
+        _view:  Ref<'query, ()>,
+    } // struct ..
+
+
+    pub struct ComponentsMut<'query, B: BitField, F: BitField, P: Hash + Eq + Debug, C: Component> {
+        query:  &'query Query<'query, B, F, P>,
+        column: &'query ComponentColumn<C>,
+        _view:  RefMut<'query, ()>,
     } // struct ..
 
 
     pub struct QueryBuilder<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        pub(crate) bit_mask: B,
-        pub(crate) world:    &'world World<B, F, P>,
+        bit_mask:         B,
+        include_disabled: bool,
+        filters:          Vec<QueryFilter<B, F, P>>,
+        access:           QueryAccess<B, F, P>,
+        world:            &'world World<B, F, P>,
+    } // struct ..
+
+
+    struct QueryAccess<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        id:      usize,
+        name:    &'static str,
+        reads:   B,
+        writes:  B,
+        borrows: Rc<RefCell<HashMap<B, ColumnBorrow>>>,
+        views:   HashMap<B, RefCell<()>>,
+        world:   PhantomData<(F, P)>,
+    } // struct ..
+
+
+    pub struct QueryState<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        world:            usize,
+        name:             &'static str,
+        bit_mask:         B,
+        reads:            B,
+        writes:           B,
+        include_disabled: bool,
+        filters:          Vec<QueryFilter<B, F, P>>,
+        entities:         BTreeSet<Entity>,
+        cursor:           Option<usize>,
+    } // struct ..
+
+
+    #[derive(Default)]
+    pub(crate) struct ColumnBorrow {
+        readers: Vec<(usize, &'static str)>,
+        writer:  Option<(usize, &'static str)>,
     } // struct ..
 
 
@@ -35,17 +101,378 @@
 //###############################
 
     impl<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> Query<'world, B, F, P> {
-        pub fn get_components<C: Component>(&self) -> Result<Vec<&Rc<RefCell<C>>>, EcsErr<B, F, P>> {
-            self.world.get_entity_group_component(&self.entities)
+        pub fn get_components<'query, C: Component>(&'query self) -> Result<impl Iterator<Item = &'query ComponentCell<C>> + 'query, EcsErr<B, F, P>> {
+
+            let component_column = self.world.get_component_column::<C>()?;
+            match self.iter_entities().find(|entity| !component_column.contains_key(entity)) {
+                Some(entity) => Err(EcsErr::MissingComponentToEntity(ComponentType::of::<C>(), entity)),
+                None         => Ok(self.iter_components::<C>()?.map(|(_, component)| component)),
+            } // match ..
+
+        } // fn ..
+
+
+        pub fn components<'query, C: Component>(&'query self) -> BorrowedRef<'query, B, F, P, C> {
+
+            let bit_mask         = self.access.declared::<C>(self.world, false)?;
+            let view             = self.access.view(bit_mask, ComponentType::of::<C>())?;
+            let component_column = self.world.get_component_column::<C>()?;
+            self.check_borrows(component_column, None)?;
+
+            Ok(ComponentsRef {
+                query:  self,
+                column: component_column,
+                _view:  view.try_borrow().map_err(|_| EcsErr::ConflictingQueries(self.access.name, self.access.name, ComponentType::of::<C>()))?,
+            }) // Ok()
+        } // fn ..
+
+
+        pub fn components_mut<'query, C: Component>(&'query self) -> BorrowedMut<'query, B, F, P, C> {
+
+            let bit_mask         = self.access.declared::<C>(self.world, true)?;
+            let view             = self.access.view(bit_mask, ComponentType::of::<C>())?;
+            let component_column = self.world.get_component_column::<C>()?;
+            self.check_borrows(component_column, Some(bit_mask))?;
+
+            Ok(ComponentsMut {
+                query:  self,
+                column: component_column,
+                _view:  view.try_borrow_mut().map_err(|_| EcsErr::ConflictingQueries(self.access.name, self.access.name, ComponentType::of::<C>()))?,
+            }) // Ok()
+        } // fn ..
+
+
+        pub fn par_for_each<C: Component + Send>(
+            &self,
+            batch_size: usize,
+            f:          impl Fn(Entity, &mut C) + Sync,
+        ) -> Result<(), EcsErr<B, F, P>> {
+
+            let mut components = self.components_mut::<C>()?;
+            let mut guards     = components.iter_mut().collect::<Vec<(Entity, RefMut<C>)>>();
+            let mut components = guards
+                .iter_mut()
+                .map(|(entity, component)| (*entity, &mut **component))
+                .collect::<Vec<(Entity, &mut C)>>();
+            let batch_size     = batch_size.max(1usize);
+            let workers        = thread::available_parallelism()
+                .map_or(1usize, |workers| workers.get())
+                .min(components.len().div_ceil(batch_size));
+            let batches        = Mutex::new(components.chunks_mut(batch_size));
+
+            thread::scope(|scope| {
+                for _ in 0..workers {
+                    scope.spawn(|| loop {
+
+                        let batch = match batches.lock() {
+                            Ok(mut batches) => batches.next(),
+                            Err(_)          => None,
+                        }; // let ..
+
+                        match batch {
+                            Some(batch) => batch.iter_mut().for_each(|(entity, component)| f(*entity, component)),
+                            None        => break,
+                        } // match ..
+                    }); // spawn()
+                } // for ..
+            }); // scope()
+
+            Ok(())
+
+        } // fn ..
+
+
+        pub fn get_entities(&self) -> Vec<Entity> { self.iter_entities().collect() }
+
+
+        pub fn iter_entities<'query>(&'query self) -> impl Iterator<Item = Entity> + 'query {
+
+            let world: &'query World<B, F, P> = self.world;
+            self.matched_entities().filter(move |entity| self.filters.iter().all(|filter| filter(world, *entity)))
+
         } // fn ..
 
 
-        pub fn get_entities(&self) -> Vec<Entity> { self.entities.clone() }
+        fn matches(&self, entity: Entity) -> bool {
+            self.world.entities
+                .get(&entity)
+                .is_some_and(|bit_mask| self.world.matches_bit_mask(*bit_mask, self.bit_mask, self.include_disabled))
+                && self.filters.iter().all(|filter| filter(self.world, entity))
+        } // fn ..
+
+
+        fn check_borrows<C: Component>(&self, component_column: &ComponentColumn<C>, lent_bit_mask: Option<B>) -> Result<(), EcsErr<B, F, P>> {
+            for entity in self.iter_entities() {
+
+                let borrowed = match (component_column.get(&entity), lent_bit_mask) {
+                    (None, _)             => return Err(EcsErr::MissingComponentToEntity(ComponentType::of::<C>(), entity)),
+                    (Some(cell), None)    => cell.try_borrow().is_err(),
+                    (Some(cell), Some(_)) => {
+
+                        self.world.lend_cell(lent_bit_mask, cell);
+                        cell.try_borrow_mut().is_err()
+
+                    }, // => ..
+                }; // let ..
+
+                if borrowed { return Err(EcsErr::BorrowedComponent(ComponentType::of::<C>(), entity)) }
 
+            } // for ..
+
+            Ok(())
+
+        } // fn ..
+
+
+        fn matched_entities<'query>(&'query self) -> impl Iterator<Item = Entity> + 'query {
+
+            let world: &'query World<B, F, P> = self.world;
+            let listed                         = self.entities.iter().flatten().copied();
+            let matched                        = self.entities
+                .is_none()
+                .then(|| world.entities
+                    .iter()
+                    .filter(|(_, bit_mask)| world.matches_bit_mask(**bit_mask, self.bit_mask, self.include_disabled))
+                    .map(|(entity, _)| *entity)
+                ).into_iter()
+                .flatten();
+
+            listed.chain(matched)
+
+        } // fn ..
+
+
+        pub fn iter_components<'query, C: Component>(&'query self) -> Result<impl Iterator<Item = (Entity, &'query ComponentCell<C>)> + 'query, EcsErr<B, F, P>> {
+
+            let world: &'query World<B, F, P>                = self.world;
+            let bit_mask                                     = world.component_bit_mask::<C>()?;
+            let component_column: &'query ComponentColumn<C> = world.get_component_column::<C>()?;
+            Ok(self
+                .iter_entities()
+                .filter_map(move |entity| component_column
+                    .get(&entity)
+                    .inspect(|component| world.lend_cell(Some(bit_mask), *component))
+                    .map(|component| (entity, component))
+                ) // filter_map()
+            ) // Ok()
+        } // fn ..
+
+
+        pub fn count(&self)                    -> usize          { self.iter_entities().count() }
+        pub fn is_empty(&self)                 -> bool           { self.first().is_none() }
+        pub fn first(&self)                    -> Option<Entity> { self.iter_entities().next() }
+        pub fn contains(&self, entity: Entity) -> bool           { self.iter_entities().any(|other| other == entity) }
+
+
+        pub fn single(&self) -> Option<Entity> {
+
+            let mut entities = self.iter_entities();
+            match (entities.next(), entities.next()) {
+                (Some(entity), None) => Some(entity),
+                _                    => None,
+            } // match ..
+
+        } // fn ..
+
+
+        pub fn get_single(&self) -> Result<Entity, EcsErr<B, F, P>> {
+            match self.single() {
+                Some(entity) => Ok(entity),
+                None         => Err(EcsErr::NotSingleQuery(self.access.name, self.count())),
+            } // match ..
+        } // fn ..
+
+
+        pub fn sorted_by_key<K: Ord>(mut self, f: impl FnMut(&Entity) -> K) -> Self {
+
+            let mut entities = self.matched_entities().collect::<Vec<Entity>>();
+            entities.sort_by_key(f);
+
+            self.entities = Some(entities);
+            self
+
+        } // fn ..
+
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug, C: Component> ComponentsRef<'_, B, F, P, C> {
+        pub fn len(&self)      -> usize { self.query.count() }
+        pub fn is_empty(&self) -> bool  { self.query.is_empty() }
+
+
+        pub fn iter(&self) -> impl Iterator<Item = (Entity, Ref<'_, C>)> {
+            self.query
+                .iter_entities()
+                .filter_map(|entity| self.column.get(&entity).map(|cell| (entity, cell.borrow())))
+        } // fn ..
+
+
+        pub fn get(&self, entity: Entity) -> Option<Ref<'_, C>> {
+            self.column
+                .get(&entity)
+                .filter(|_| self.query.matches(entity))
+                .map(|cell| cell.borrow())
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug, C: Component> ComponentsMut<'_, B, F, P, C> {
+        pub fn len(&self)      -> usize { self.query.count() }
+        pub fn is_empty(&self) -> bool  { self.query.is_empty() }
+
+
+        pub fn iter(&self) -> impl Iterator<Item = (Entity, Ref<'_, C>)> {
+            self.query
+                .iter_entities()
+                .filter_map(|entity| self.column.get(&entity).map(|cell| (entity, cell.borrow())))
+        } // fn ..
+
+
+        pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, RefMut<'_, C>)> {
+            self.query
+                .iter_entities()
+                .filter_map(|entity| self.column.get(&entity).map(|cell| (entity, cell.borrow_mut())))
+        } // fn ..
+
+
+        pub fn get_mut(&mut self, entity: Entity) -> Option<RefMut<'_, C>> {
+            self.column
+                .get(&entity)
+                .filter(|_| self.query.matches(entity))
+                .map(|cell| cell.borrow_mut())
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> QueryAccess<B, F, P> {
+        fn new(name: &'static str, world: &World<B, F, P>) -> Self {
+
+            world.next_query_id.set(world.next_query_id.get() + 1);
+            QueryAccess {
+                id:      world.next_query_id.get(),
+                name,
+                reads:   B::MIN,
+                writes:  B::MIN,
+                borrows: world.column_borrows.clone(),
+                views:   HashMap::default(),
+                world:   PhantomData,
+            } // QueryAccess
+        } // fn ..
+
+
+        fn conflict(&self, bit_mask: B, kind: ComponentType, mutable: bool) -> Result<(), EcsErr<B, F, P>> {
+
+            let borrows = self.borrows.borrow();
+            match borrows
+                .get(&bit_mask)
+                .and_then(|borrow| borrow.writer
+                    .iter()
+                    .chain(borrow.readers.iter().filter(|_| mutable))
+                    .find(|(id, _)| *id != self.id)
+                ) {
+                    Some((_, other)) => Err(EcsErr::ConflictingQueries(self.name, other, kind)),
+                    None             => Ok(()),
+                } // match ..
+        } // fn ..
+
+
+        fn declared<C: Component>(&self, world: &World<B, F, P>, mutable: bool) -> Result<B, EcsErr<B, F, P>> {
+
+            let bit_mask = world.component_bit_mask::<C>()?;
+            match (mutable, self.writes.has_bits(bit_mask), self.reads.has_bits(bit_mask)) {
+                (true, false, _)      => Err(EcsErr::UndeclaredAccess(self.name, "write", ComponentType::of::<C>())),
+                (false, false, false) => Err(EcsErr::UndeclaredAccess(self.name, "read", ComponentType::of::<C>())),
+                _                     => Ok(bit_mask),
+            } // match ..
+        } // fn ..
+
+
+        fn view(&self, bit_mask: B, kind: ComponentType) -> Result<&RefCell<()>, EcsErr<B, F, P>> {
+            match self.views.get(&bit_mask) {
+                Some(view) => Ok(view),
+                None       => Err(EcsErr::UndeclaredAccess(self.name, "read", kind)),
+            } // match ..
+        } // fn ..
+
+
+        fn borrow<C: Component>(&mut self, world: &World<B, F, P>, mutable: bool) -> Result<B, EcsErr<B, F, P>> {
+
+            let bit_mask = world.component_bit_mask::<C>()?;
+            self.register(bit_mask, ComponentType::of::<C>(), mutable)?;
+            Ok(bit_mask)
+
+        } // fn ..
+
+
+        fn register(&mut self, bit_mask: B, kind: ComponentType, mutable: bool) -> Result<(), EcsErr<B, F, P>> {
+
+            self.conflict(bit_mask, kind, mutable)?;
+            self.views.entry(bit_mask).or_default();
+            let mut borrows = self.borrows.borrow_mut();
+            let borrow      = borrows.entry(bit_mask).or_default();
+
+            match mutable {
+                true  => { borrow.writer = Some((self.id, self.name)); self.writes |= bit_mask; },
+                false => { borrow.readers.push((self.id, self.name)); self.reads |= bit_mask; },
+            } // match ..
+
+            Ok(())
+
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Drop for QueryAccess<B, F, P> {
+        fn drop(&mut self) {
+            if let Ok(mut borrows) = self.borrows.try_borrow_mut() {
+                for borrow in borrows.values_mut() {
+
+                    borrow.readers.retain(|(id, _)| *id != self.id);
+                    if borrow.writer.is_some_and(|(id, _)| id == self.id) { borrow.writer = None; }
+
+                } // for ..
+            } // if ..
+        } // fn ..
     } // impl ..
 
 
     impl<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> QueryBuilder<'world, B, F, P> {
+        pub(crate) fn new(world: &'world World<B, F, P>) -> Self {
+            QueryBuilder {
+                bit_mask:         B::MIN,
+                include_disabled: false,
+                filters:          Vec::default(),
+                access:           QueryAccess::new("query", world),
+                world,
+            } // QueryBuilder
+        } // fn ..
+
+
+        pub fn named(mut self, name: &'static str) -> Self {
+
+            self.access.name = name;
+            self
+
+        } // fn ..
+
+
+        pub fn read<C: Component>(mut self) -> Result<Self, EcsErr<B, F, P>> {
+
+            self.bit_mask |= self.access.borrow::<C>(self.world, false)?;
+            Ok(self)
+
+        } // fn ..
+
+
+        pub fn write<C: Component>(mut self) -> Result<Self, EcsErr<B, F, P>> {
+
+            self.bit_mask |= self.access.borrow::<C>(self.world, true)?;
+            Ok(self)
+
+        } // fn ..
+
+
         pub fn with_component<C: Component>(mut self) -> Result<Self, EcsErr<B, F, P>> {
 
             self.bit_mask |= self.world.component_bit_mask::<C>()?;
@@ -54,6 +481,39 @@
         } // fn ..
 
 
+        pub fn filter<C: Component>(mut self, predicate: impl Fn(&C) -> bool + 'static) -> Result<Self, EcsErr<B, F, P>> {
+
+            self.bit_mask |= self.world.component_bit_mask::<C>()?;
+            self.filters.push(Rc::new(move |world: &World<B, F, P>, entity| world
+                .get_component_column::<C>()
+                .ok()
+                .and_then(|component_column| component_column.get(&entity))
+                .and_then(|component| component.try_borrow().ok())
+                .is_some_and(|component| predicate(&component))
+            )); // push()
+
+            Ok(self)
+
+        } // fn ..
+
+
+        pub fn with_entities(mut self, entities: impl IntoIterator<Item = Entity>) -> Self {
+
+            let entities = entities.into_iter().collect::<HashSet<Entity>>();
+            self.filters.push(Rc::new(move |_: &World<B, F, P>, entity| entities.contains(&entity)));
+            self
+
+        } // fn ..
+
+
+        pub fn include_disabled(mut self) -> Self {
+
+            self.include_disabled = true;
+            self
+
+        } // fn ..
+
+
         pub fn with_flag<T: Into<F>>(mut self, flag: T, variant: Option<B>) -> Result<Self, EcsErr<B, F, P>> {
 
             self.bit_mask |= self.world.flag_bit_mask(flag.into(), variant)?;
@@ -64,11 +524,100 @@
 
         pub fn build(self) -> Query<'world, B, F, P> {
 
-            let entities = self.world.get_entities(self.bit_mask);
+            let entities = self.world.deterministic_iteration.then(|| self.world.get_entities(self.bit_mask, self.include_disabled));
 
             Query {
                 entities,
-                world: self.world,
+                bit_mask:         self.bit_mask,
+                include_disabled: self.include_disabled,
+                filters:          self.filters,
+                access:           self.access,
+                world:            self.world,
             } // Query
         } // fn ..
+
+
+        pub fn into_state(self) -> QueryState<B, F, P> {
+            QueryState {
+                world:            self.world.id,
+                name:             self.access.name,
+                bit_mask:         self.bit_mask,
+                reads:            self.access.reads,
+                writes:           self.access.writes,
+                include_disabled: self.include_disabled,
+                filters:          self.filters,
+                entities:         BTreeSet::default(),
+                cursor:           None,
+            } // QueryState
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> QueryState<B, F, P> {
+        pub fn len(&self)      -> usize { self.entities.len() }
+        pub fn is_empty(&self) -> bool  { self.entities.is_empty() }
+
+
+        pub fn iter_entities(&self) -> Copied<btree_set::Iter<'_, Entity>> { self.entities.iter().copied() }
+
+
+        pub fn update(&mut self, world: &World<B, F, P>) -> Result<(), EcsErr<B, F, P>> {
+
+            if world.id != self.world { return Err(EcsErr::ForeignQueryState(self.name)) }
+
+            match self.cursor {
+                Some(cursor) if cursor >= world.signature_log_start => world.signature_log
+                    .iter()
+                    .skip(cursor - world.signature_log_start)
+                    .for_each(|entity| match world.entities.get(entity) {
+                        Some(bit_mask) if world.matches_bit_mask(*bit_mask, self.bit_mask, self.include_disabled) => { self.entities.insert(*entity); },
+                        _                                                                                          => { self.entities.remove(entity); },
+                    }), // => ..
+                _ => self.entities = world
+                    .get_entities(self.bit_mask, self.include_disabled)
+                    .into_iter()
+                    .collect(),
+            } // match ..
+
+            self.cursor = Some(world.signature_log_start + world.signature_log.len());
+            Ok(())
+
+        } // fn ..
+
+
+        pub fn query<'world>(&mut self, world: &'world World<B, F, P>) -> Result<Query<'world, B, F, P>, EcsErr<B, F, P>> {
+
+            self.update(world)?;
+
+            let mut access = QueryAccess::new(self.name, world);
+            for (bit_mask, info) in world.components.iter().enumerate().map(|(index, info)| (B::nth_bit(index as u8), info)) {
+
+                if self.writes.has_bits(bit_mask)     { access.register(bit_mask, info.kind, true)?; }
+                else if self.reads.has_bits(bit_mask) { access.register(bit_mask, info.kind, false)?; }
+
+            } // for ..
+
+            Ok(Query {
+                entities:         Some(self.entities.iter().copied().collect()),
+                bit_mask:         self.bit_mask,
+                include_disabled: self.include_disabled,
+                filters:          self.filters.clone(),
+                access,
+                world,
+            }) // Ok()
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub(crate) fn log_signature(&mut self, entity: Entity) {
+
+            self.signature_log.push_back(entity);
+            while self.signature_log.len() > self.entities.len().max(SIGNATURE_LOG_MIN_LEN) {
+
+                self.signature_log.pop_front();
+                self.signature_log_start += 1usize;
+
+            } // while ..
+        } // fn ..
     } // impl ..
@@ -0,0 +1,337 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, HashSet};
+    use std::fmt::Debug;
+    use std::hash::Hash;
+    use std::str::FromStr;
+
+    use crate::worlds::World;
+    use crate::errors::EcsErr;
+    use crate::components::Component;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    type SceneRecords<B, F>            = Vec<(String, SceneEntity<B, F>)>;
+    type AddSceneComponent<B, F, P>    = fn(&mut World<B, F, P>, Entity, &str, usize) -> Result<(), EcsErr<B, F, P>>;
+    type DeleteSceneComponent<B, F, P> = fn(&mut World<B, F, P>, Entity) -> Result<(), EcsErr<B, F, P>>;
+
+
+    pub trait SceneComponent: Component {
+        fn from_scene(value: &str) -> Option<Self>;
+    } // trait ..
+
+
+    pub struct Scene<B: BitField, F: BitField> {
+        entities: HashMap<String, (Entity, SceneEntity<B, F>)>,
+    } // struct ..
+
+
+    #[derive(Clone, PartialEq)]
+    pub(crate) struct SceneEntity<B: BitField, F: BitField> {
+        components: HashMap<String, String>,
+        lines:      HashMap<String, usize>,
+        flags:      Vec<(F, Option<B>)>,
+        parent:     Option<String>,
+    } // struct ..
+
+
+    pub(crate) struct SceneComponentLoader<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        pub(crate) validate: fn(&str) -> bool,
+        pub(crate) add:      AddSceneComponent<B, F, P>,
+        pub(crate) delete:   DeleteSceneComponent<B, F, P>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField, F: BitField> Default for Scene<B, F> {
+        fn default() -> Self { Scene { entities: HashMap::default() }}
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField> Default for SceneEntity<B, F> {
+        fn default() -> Self {
+            SceneEntity {
+                components: HashMap::default(),
+                lines:      HashMap::default(),
+                flags:      Vec::default(),
+                parent:     None,
+            } // SceneEntity
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> SceneComponentLoader<B, F, P> {
+        pub(crate) fn new<C: SceneComponent>() -> Self {
+            SceneComponentLoader {
+                validate: |value| C::from_scene(value).is_some(),
+                add:      |world, entity, value, line_number| match C::from_scene(value) {
+                    Some(component) => world.add_component_to_entity(component, entity),
+                    None            => Err(EcsErr::InvalidScene(line_number, format!("invalid component value `{}`", value))),
+                }, // add ..
+                delete:   |world, entity| world.delete_entity_component::<C>(entity),
+            } // SceneComponentLoader
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField> Scene<B, F> {
+        pub fn entity(&self, name: &str) -> Option<Entity> { self.entities.get(name).map(|(entity, _)| *entity) }
+
+
+        pub fn entities(&self) -> impl Iterator<Item = (&str, Entity)> {
+            self.entities
+                .iter()
+                .map(|(name, (entity, _))| (name.as_str(), *entity))
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField + FromStr, F: BitField + FromStr> Scene<B, F> {
+        pub(crate) fn reload<P: Hash + Eq + Debug>(
+            &mut self,
+            world:  &mut World<B, F, P>,
+            source: &str,
+        ) -> Result<(), EcsErr<B, F, P>> {
+
+            let records  = parse_scene(world, source)?;
+            let entities = self.entities.clone();
+
+            match world.atomically(|world| self.apply(world, records)) {
+                Ok(())   => Ok(()),
+                Err(err) => {
+
+                    self.entities = entities;
+                    Err(err)
+
+                }, // => ..
+            } // match ..
+        } // fn ..
+
+
+        fn apply<P: Hash + Eq + Debug>(
+            &mut self,
+            world:   &mut World<B, F, P>,
+            records: SceneRecords<B, F>,
+        ) -> Result<(), EcsErr<B, F, P>> {
+
+            let names = records.iter().map(|(name, _)| name.as_str()).collect::<HashSet<&str>>();
+
+            let removed = self.entities
+                .keys()
+                .filter(|name| !names.contains(name.as_str()))
+                .cloned()
+                .collect::<Vec<String>>();
+
+            for name in removed {
+                if let Some((entity, _)) = self.entities.remove(&name) { world.delete_entity(entity)?; }
+            } // for ..
+
+            for (name, _) in records.iter() {
+                if !self.entities.contains_key(name) {
+
+                    let entity = world.new_entity().build();
+                    self.entities.insert(name.clone(), (entity, SceneEntity::default()));
+
+                } // if ..
+            } // for ..
+
+
+            for (name, record) in records.iter() {
+
+                let (entity, previous) = &self.entities[name];
+                let entity             = *entity;
+
+                for (component, value) in record.components.iter() {
+                    if previous.components.get(component) != Some(value) {
+                        let line_number = record.lines.get(component).copied().unwrap_or_default();
+                        if let Some(add) = world.scene_component_loader(component).map(|loader| loader.add) { add(world, entity, value, line_number)?; }
+                    } // if ..
+                } // for ..
+
+                for component in previous.components.keys() {
+                    if !record.components.contains_key(component) {
+                        if let Some(delete) = world.scene_component_loader(component).map(|loader| loader.delete) { delete(world, entity)?; }
+                    } // if ..
+                } // for ..
+
+                if previous.flags != record.flags {
+
+                    for (flag, _) in previous.flags.iter().chain(record.flags.iter()) { world.remove_entity_flag(entity, *flag, None)?; }
+                    for (flag, variant) in record.flags.iter() { world.set_entity_flag(entity, *flag, *variant)?; }
+
+                } // if ..
+            } // for ..
+
+
+            for (name, record) in records.iter() {
+
+                let (entity, previous) = &self.entities[name];
+                if previous.parent != record.parent {
+                    match record.parent.as_ref().and_then(|parent| self.entity(parent)) {
+                        Some(parent) => world.set_entity_parent(*entity, parent)?,
+                        None         => world.remove_entity_parent(*entity)?,
+                    } // match ..
+                } // if ..
+            } // for ..
+
+
+            for (name, record) in records {
+                if let Some((_, previous)) = self.entities.get_mut(&name) { *previous = record; }
+            } // for ..
+
+            Ok(())
+
+        } // fn ..
+    } // impl ..
+
+
+    pub(crate) fn split_word(line: &str) -> (&str, &str) {
+        match line.split_once(char::is_whitespace) {
+            Some((word, rest)) => (word, rest.trim()),
+            None               => (line, ""),
+        } // match ..
+    } // fn ..
+
+
+    fn strip_comment(line: &str) -> String {
+
+        let mut stripped = String::with_capacity(line.len());
+        let mut chars    = line.chars().peekable();
+
+        while let Some(character) = chars.next() {
+            match character {
+                '\\' if chars.peek() == Some(&'#') => { stripped.push('#'); chars.next(); },
+                '#'                                => break,
+                character                          => stripped.push(character),
+            } // match ..
+        } // while ..
+
+        stripped
+
+    } // fn ..
+
+
+    fn parse_scene<B: BitField + FromStr, F: BitField + FromStr, P: Hash + Eq + Debug>(
+        world:  &World<B, F, P>,
+        source: &str,
+    ) -> Result<SceneRecords<B, F>, EcsErr<B, F, P>> {
+
+        let mut prefabs  = HashMap::<String, SceneEntity<B, F>>::new();
+        let mut entities = SceneRecords::<B, F>::new();
+        let mut parents  = Vec::<(usize, String)>::new();
+        let mut current  = None::<(bool, String, SceneEntity<B, F>)>;
+
+        for (index, line) in source.lines().enumerate() {
+
+            let line_number     = index + 1usize;
+            let invalid         = |message: String| EcsErr::InvalidScene(line_number, message);
+            let line            = strip_comment(line);
+            let (keyword, rest) = split_word(line.trim());
+
+            match keyword {
+                ""                    => continue,
+                "prefab" | "entity"   => {
+
+                    if let Some((is_prefab, name, record)) = current.take() {
+                        match is_prefab {
+                            true  => { prefabs.insert(name, record); },
+                            false => entities.push((name, record)),
+                        } // match ..
+                    } // if ..
+
+                    let (name, base) = split_word(rest);
+                    if name.is_empty() { return Err(invalid(format!("missing {} name", keyword))) }
+                    if prefabs.contains_key(name) || entities.iter().any(|(other, _)| other == name) {
+                        return Err(invalid(format!("`{}` is declared twice", name)))
+                    } // if ..
+
+                    let record = match base {
+                        ""   => SceneEntity::default(),
+                        base => match prefabs.get(base) {
+                            Some(prefab) => prefab.clone(),
+                            None         => return Err(invalid(format!("unknown prefab `{}`", base))),
+                        } // match ..
+                    }; // let ..
+
+                    current = Some((keyword == "prefab", name.to_string(), record));
+
+                }, // => ..
+                "component" => {
+
+                    let (name, value) = split_word(rest);
+                    let record        = match current.as_mut() {
+                        Some((_, _, record)) => record,
+                        None                 => return Err(invalid(String::from("`component` outside of a prefab or entity"))),
+                    }; // let ..
+
+                    match world.scene_component_loader(name) {
+                        None                                         => return Err(invalid(format!("unknown component `{}`", name))),
+                        Some(loader) if !(loader.validate)(value)    => return Err(invalid(format!("invalid `{}` value `{}`", name, value))),
+                        Some(_)                                      => {
+
+                            record.components.insert(name.to_string(), value.to_string());
+                            record.lines.insert(name.to_string(), line_number);
+
+                        }, // => ..
+                    } // match ..
+                }, // => ..
+                "flag" => {
+
+                    let (flag, variant) = split_word(rest);
+                    let record          = match current.as_mut() {
+                        Some((_, _, record)) => record,
+                        None                 => return Err(invalid(String::from("`flag` outside of a prefab or entity"))),
+                    }; // let ..
+
+                    let flag = match F::from_str(flag) {
+                        Ok(flag) => flag,
+                        Err(_)   => return Err(invalid(format!("invalid flag `{}`", flag))),
+                    }; // let ..
+
+                    let variant = match variant {
+                        ""      => None,
+                        variant => match B::from_str(variant) {
+                            Ok(variant) => Some(variant),
+                            Err(_)      => return Err(invalid(format!("invalid flag variant `{}`", variant))),
+                        } // match ..
+                    }; // let ..
+
+                    record.flags.retain(|(other, _)| *other != flag);
+                    record.flags.push((flag, variant));
+
+                }, // => ..
+                "parent" => match current.as_mut() {
+                    Some((false, _, record)) if !rest.is_empty() => {
+
+                        record.parent = Some(rest.to_string());
+                        parents.push((line_number, rest.to_string()));
+
+                    }, // => ..
+                    Some((false, _, _)) => return Err(invalid(String::from("missing parent name"))),
+                    _                   => return Err(invalid(String::from("`parent` outside of an entity"))),
+                }, // => ..
+                keyword => return Err(invalid(format!("unknown keyword `{}`", keyword))),
+            } // match ..
+        } // for ..
+
+
+        if let Some((false, name, record)) = current { entities.push((name, record)); }
+
+        match parents
+            .into_iter()
+            .find(|(_, parent)| !entities.iter().any(|(name, _)| name == parent)) {
+                Some((line_number, parent)) => Err(EcsErr::InvalidScene(line_number, format!("unknown parent entity `{}`", parent))),
+                None                        => Ok(entities),
+            } // match ..
+    } // fn ..
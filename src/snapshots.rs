@@ -0,0 +1,286 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, VecDeque};
+    use std::fmt::Debug;
+    use std::hash::Hash;
+
+    use crate::worlds::World;
+    use crate::errors::EcsErr;
+    use crate::components::ComponentCell;
+    use crate::entities::{Entity, EntityId};
+    use crate::history::History;
+    use crate::transactions::Change;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub(crate) struct SnapshotBuffer<B: BitField> {
+        capacity:  usize,
+        snapshots: VecDeque<Snapshot<B>>,
+    } // struct ..
+
+
+    struct Snapshot<B: BitField> {
+        tick:           u64,
+        entities:       HashMap<Entity, B>,
+        parents:        HashMap<Entity, Entity>,
+        next_entity_id: EntityId,
+        columns:        Vec<(B, Vec<Entity>, Vec<SavedCell>)>,
+        pointers:       Vec<SavedCell>,
+    } // struct ..
+
+
+    pub(crate) struct Checkpoint<B: BitField> {
+        snapshot:    Snapshot<B>,
+        history:     Option<History<B>>,
+        changes:     Option<Vec<Change<B>>>,
+        journal_len: Option<usize>,
+    } // struct ..
+
+
+    struct SavedCell {
+        handle: Box<dyn ComponentCell>,
+        value:  Box<dyn ComponentCell>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> Snapshot<B> {
+        fn new(tick: u64) -> Self {
+            Snapshot {
+                tick,
+                entities:       HashMap::default(),
+                parents:        HashMap::default(),
+                next_entity_id: EntityId::default(),
+                columns:        Vec::default(),
+                pointers:       Vec::default(),
+            } // Snapshot
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField> SnapshotBuffer<B> {
+        pub(crate) fn new(capacity: usize) -> Self {
+            SnapshotBuffer {
+                capacity,
+                snapshots: VecDeque::with_capacity(capacity),
+            } // SnapshotBuffer
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn save_snapshot(&mut self, tick: u64) {
+
+            let reused = match self.snapshots.as_mut() {
+                None         => return,
+                Some(buffer) => match buffer.snapshots.iter().position(|snapshot| snapshot.tick == tick) {
+                    Some(index)                                        => buffer.snapshots.remove(index),
+                    None if buffer.snapshots.len() >= buffer.capacity => buffer.snapshots.pop_front(),
+                    None                                               => None,
+                }, // => ..
+            }; // let ..
+
+            let mut snapshot = reused.unwrap_or_else(|| Snapshot::new(tick));
+            snapshot.tick    = tick;
+            self.capture(&mut snapshot);
+
+            if let Some(buffer) = self.snapshots.as_mut() {
+
+                buffer.snapshots.push_back(snapshot);
+                while buffer.snapshots.len() > buffer.capacity { buffer.snapshots.pop_front(); }
+
+            } // if ..
+        } // fn ..
+
+
+        pub(crate) fn checkpoint(&self) -> Checkpoint<B> {
+
+            let mut snapshot = Snapshot::new(0u64);
+            self.capture(&mut snapshot);
+
+            Checkpoint {
+                snapshot,
+                history:     self.history.clone(),
+                changes:     self.changes.clone(),
+                journal_len: self.journal.as_ref().map(|journal| journal.len()),
+            } // Checkpoint
+        } // fn ..
+
+
+        pub(crate) fn rollback(&mut self, checkpoint: Checkpoint<B>) {
+
+            self.restore(&checkpoint.snapshot);
+            self.history = checkpoint.history;
+            self.changes = checkpoint.changes;
+
+            if let (Some(journal), Some(len)) = (self.journal.as_mut(), checkpoint.journal_len) { journal.truncate(len); }
+
+        } // fn ..
+
+
+        fn capture(&self, snapshot: &mut Snapshot<B>) {
+
+            snapshot.next_entity_id = self.next_entity_id;
+            snapshot.entities.clone_from(&self.entities);
+            snapshot.parents.clone_from(&self.parents);
+
+            let mut saved_columns = 0usize;
+            for bit_mask in (0..self.components.len()).map(|index| B::nth_bit(index as u8)) {
+
+                let column = match self.component_columns.get(&bit_mask) {
+                    Some(column) => column,
+                    None         => continue,
+                }; // let ..
+
+                if snapshot.columns.len() <= saved_columns { snapshot.columns.push((bit_mask, Vec::default(), Vec::default())); }
+
+                let (saved_bit_mask, entities, cells) = &mut snapshot.columns[saved_columns];
+                *saved_bit_mask = bit_mask;
+                entities.clear();
+
+                column.for_each_cell(&mut |entity, cell| {
+
+                    save_cell(cells, entities.len(), cell);
+                    entities.push(entity);
+
+                }); // for_each_cell()
+
+                cells.truncate(entities.len());
+                saved_columns += 1usize;
+
+            } // for ..
+
+            snapshot.columns.truncate(saved_columns);
+
+            let mut saved_pointers = 0usize;
+            for cell in self.component_pointers.values() {
+
+                save_cell(&mut snapshot.pointers, saved_pointers, cell.as_ref());
+                saved_pointers += 1usize;
+
+            } // for ..
+
+            snapshot.pointers.truncate(saved_pointers);
+
+        } // fn ..
+
+
+        pub fn snapshot_ticks(&self) -> Vec<u64> {
+            self.snapshots
+                .iter()
+                .flat_map(|buffer| buffer.snapshots.iter().map(|snapshot| snapshot.tick))
+                .collect()
+        } // fn ..
+
+
+        pub fn restore_snapshot(&mut self, tick: u64) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("restore_snapshot", |world| {
+
+                let buffer = match world.snapshots.take() {
+                    Some(buffer) => buffer,
+                    None         => return Err(EcsErr::MissingSnapshot(tick)),
+                }; // let ..
+
+                let result = match buffer.snapshots.iter().find(|snapshot| snapshot.tick == tick) {
+                    Some(snapshot) => { world.restore(snapshot); Ok(()) },
+                    None           => Err(EcsErr::MissingSnapshot(tick)),
+                }; // let ..
+
+                world.snapshots = Some(buffer);
+                result
+
+            }) // during_mut()
+        } // fn ..
+
+
+        fn restore(&mut self, snapshot: &Snapshot<B>) {
+
+            let despawned = self.entities
+                .keys()
+                .filter(|entity| !snapshot.entities.contains_key(entity))
+                .copied()
+                .collect::<Vec<Entity>>();
+
+            for entity in despawned { self.despawn_entity(entity); }
+
+
+            let reshaped = self.entities
+                .iter()
+                .map(|(entity, bit_mask)| (*entity, *bit_mask, snapshot.entities.get(entity).copied().unwrap_or(B::MIN)))
+                .filter(|(_, bit_mask, saved_bit_mask)| bit_mask != saved_bit_mask)
+                .collect::<Vec<(Entity, B, B)>>();
+
+            for (bit_mask, entities, cells) in snapshot.columns.iter() {
+
+                let removed = reshaped
+                    .iter()
+                    .filter(|(_, entity_bit_mask, saved_bit_mask)| entity_bit_mask.has_bits(*bit_mask) && !saved_bit_mask.has_bits(*bit_mask))
+                    .map(|(entity, _, _)| *entity)
+                    .collect::<Vec<Entity>>();
+
+                let eq    = self.column_info(*bit_mask).and_then(|info| info.eq);
+                let stale = self.component_columns
+                    .get(bit_mask)
+                    .map(|column| entities
+                        .iter()
+                        .zip(cells.iter())
+                        .filter(|(entity, cell)| column.get_cell(**entity).map(|current| current.cell_id()) != Some(cell.handle.cell_id()))
+                        .map(|(entity, cell)| (*entity, cell.handle.clone_cell()))
+                        .collect::<Vec<(Entity, Box<dyn ComponentCell>)>>()
+                    ).unwrap_or_default();
+
+                for entity in removed { self.write_cell(*bit_mask, entity, None); }
+                for (entity, handle) in stale { self.write_cell(*bit_mask, entity, Some(handle)); }
+                for (entity, cell) in entities.iter().zip(cells.iter()) {
+                    if eq.and_then(|eq| eq(cell.handle.as_ref(), cell.value.as_ref())) != Some(true) {
+
+                        self.assign_value(Some(*bit_mask), cell.handle.as_ref(), cell.value.as_ref());
+                        self.record_entry(|world| world.journal_entry_of_value(*bit_mask, *entity, cell.handle.as_ref()));
+
+                    } // if ..
+                } // for ..
+            } // for ..
+
+
+            for (entity, bit_mask) in snapshot.entities.iter() {
+                if self.entities.get(entity) != Some(bit_mask) { self.write_bit_mask(*entity, Some(*bit_mask)); }
+            } // for ..
+
+            let unparented = self.parents
+                .keys()
+                .filter(|entity| !snapshot.parents.contains_key(entity))
+                .copied()
+                .collect::<Vec<Entity>>();
+
+            for entity in unparented { self.write_parent(entity, None); }
+            for (entity, parent) in snapshot.parents.iter() { self.write_parent(*entity, Some(*parent)); }
+
+            self.write_next_entity_id(snapshot.next_entity_id);
+            for cell in snapshot.pointers.iter() {
+
+                self.assign_value(None, cell.handle.as_ref(), cell.value.as_ref());
+                self.record_entry(|world| world.journal_entry_of_pointer(cell.handle.as_ref()));
+
+            } // for ..
+
+        } // fn ..
+    } // impl ..
+
+
+    fn save_cell(cells: &mut Vec<SavedCell>, index: usize, cell: &dyn ComponentCell) {
+        match cells.get_mut(index) {
+            Some(saved) if saved.handle.cell_id() == cell.cell_id() => saved.value.assign(cell),
+            Some(saved)                                              => *saved = SavedCell { handle: cell.clone_cell(), value: cell.clone_value() },
+            None                                                     => cells.push(SavedCell { handle: cell.clone_cell(), value: cell.clone_value() }),
+        } // match ..
+    } // fn ..
@@ -0,0 +1,390 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+    use std::hash::Hash;
+
+    use crate::worlds::World;
+    use crate::errors::{EcsErr, ComponentType};
+    use crate::components::{Component, ComponentCell};
+    use crate::entities::{Entity, EntityId};
+    use crate::journals::JournalEntry;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    type LentCell<B> = (Option<B>, Box<dyn ComponentCell>, Option<Box<dyn ComponentCell>>);
+
+
+    pub(crate) enum Change<B: BitField> {
+        BitMask(Entity, Option<B>, Option<B>),
+        Cell(B, Entity, Option<Box<dyn ComponentCell>>, Option<Box<dyn ComponentCell>>),
+        Value(Option<B>, Box<dyn ComponentCell>, Box<dyn ComponentCell>, Box<dyn ComponentCell>),
+        Parent(Entity, Option<Entity>, Option<Entity>),
+        NextEntityId(EntityId, EntityId),
+    } // enum ..
+
+
+    pub(crate) struct LentCells<B: BitField> {
+        cells: Vec<LentCell<B>>,
+        ids:   HashSet<usize>,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> Clone for Change<B> {
+        fn clone(&self) -> Self {
+            match self {
+                Change::BitMask(entity, before, after)               => Change::BitMask(*entity, *before, *after),
+                Change::Cell(column_bit_mask, entity, before, after) => Change::Cell(*column_bit_mask, *entity, before.as_ref().map(|cell| cell.clone_cell()), after.as_ref().map(|cell| cell.clone_cell())),
+                Change::Value(column_bit_mask, cell, before, after)  => Change::Value(*column_bit_mask, cell.clone_cell(), before.clone_cell(), after.clone_cell()),
+                Change::Parent(entity, before, after)                => Change::Parent(*entity, *before, *after),
+                Change::NextEntityId(before, after)                  => Change::NextEntityId(*before, *after),
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField> Default for LentCells<B> {
+        fn default() -> Self {
+            LentCells {
+                cells: Vec::default(),
+                ids:   HashSet::default(),
+            } // LentCells
+        } // fn ..
+    } // impl ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
+        pub fn transaction<R>(
+            &mut self,
+            f: impl FnOnce(&mut Self) -> Result<R, EcsErr<B, F, P>>,
+        ) -> Result<R, EcsErr<B, F, P>> {
+
+            let result = self.atomically(f).map_err(|err| err.during("transaction"));
+            self.debug_validate("transaction");
+            result
+
+        } // fn ..
+
+
+        pub(crate) fn atomically<R>(
+            &mut self,
+            f: impl FnOnce(&mut Self) -> Result<R, EcsErr<B, F, P>>,
+        ) -> Result<R, EcsErr<B, F, P>> {
+
+            self.settle_lent_cells();
+
+            let outer          = self.changes.replace(Vec::new());
+            let outer_conflict = self.index_conflict.take();
+            let journal_len    = self.journal.as_ref().map(|journal| journal.len());
+
+            self.atomic_depth += 1usize;
+            let result = f(self);
+
+            self.settle_lent_cells();
+            self.atomic_depth -= 1usize;
+
+            let result = result.and_then(|value| match self.index_conflict.take() {
+                Some(err) => Err(err),
+                None      => Ok(value),
+            }); // let ..
+
+            let changes         = std::mem::replace(&mut self.changes, outer).unwrap_or_default();
+            self.index_conflict = outer_conflict;
+
+            match result {
+                Ok(value) => {
+
+                    if let Some(outer) = self.changes.as_mut() { outer.extend(changes); }
+                    self.enforce_history_budget();
+
+                    Ok(value)
+
+                }, // => ..
+                Err(err) => {
+
+                    self.revert_changes(&changes);
+                    self.count_pending_changes(&changes, false);
+                    if let (Some(journal), Some(len)) = (self.journal.as_mut(), journal_len) { journal.truncate(len); }
+
+                    Err(err)
+
+                }, // => ..
+            } // match ..
+        } // fn ..
+
+
+        pub fn modify_entity_component<C: Component>(
+            &mut self,
+            entity: Entity,
+            f:      impl FnOnce(&mut C),
+        ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("modify_entity_component", |world| {
+
+                let bit_mask = world.component_bit_mask::<C>()?;
+                let cell     = match world.get_component_column::<C>()?.get(&entity) {
+                    Some(cell) => cell.clone(),
+                    None       => return Err(EcsErr::MissingComponentToEntity(ComponentType::of::<C>(), entity)),
+                }; // let ..
+
+                let before = world.changes.is_some().then(|| cell.clone_value());
+                f(&mut cell.borrow_mut());
+
+                if let Some(before) = before {
+                    world.record(|| Change::Value(Some(bit_mask), cell.clone_cell(), before, cell.clone_value()));
+                } // if ..
+
+                world.reindex_cell(bit_mask, &cell, true);
+
+                world.record_entry(|world| match world.journal_value(ComponentType::of::<C>(), &cell) {
+                    Ok((name, value)) => JournalEntry::Assign(entity, name, value),
+                    Err(name)         => JournalEntry::Unjournaled(name),
+                }); // record_entry()
+
+                Ok(())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub(crate) fn lend_cell(&self, column_bit_mask: Option<B>, cell: &dyn ComponentCell) {
+
+            let atomic = self.atomic_depth > 0usize;
+            if (atomic || self.is_indexed(column_bit_mask)) && !cell.is_borrowed_mut() {
+                if let Ok(mut lent_cells) = self.lent_cells.try_borrow_mut() {
+                    if lent_cells.ids.insert(cell.cell_id()) { lent_cells.cells.push((column_bit_mask, cell.clone_cell(), atomic.then(|| cell.clone_value()))); }
+                } // if ..
+            } // if ..
+        } // fn ..
+
+
+        pub(crate) fn lent_cells_of(&self, column_bit_mask: B) -> Vec<Box<dyn ComponentCell>> {
+            match self.lent_cells.try_borrow() {
+                Ok(lent_cells) => lent_cells.cells
+                    .iter()
+                    .filter(|(lent_bit_mask, _, _)| *lent_bit_mask == Some(column_bit_mask))
+                    .map(|(_, cell, _)| cell.clone_cell())
+                    .collect(),
+                Err(_)         => Vec::default(),
+            } // match ..
+        } // fn ..
+
+
+        pub(crate) fn settle_lent_cells(&mut self) {
+
+            let lent_cells = std::mem::take(self.lent_cells.get_mut());
+            for (column_bit_mask, cell, before) in lent_cells.cells {
+
+                if cell.is_borrowed_mut() {
+
+                    let lent_cells = self.lent_cells.get_mut();
+                    lent_cells.ids.insert(cell.cell_id());
+                    lent_cells.cells.push((column_bit_mask, cell, before));
+                    continue
+
+                } // if ..
+
+                if let Some(before) = before {
+
+                    let unchanged = column_bit_mask
+                        .and_then(|column_bit_mask| self.column_info(column_bit_mask))
+                        .and_then(|info| info.eq)
+                        .and_then(|eq| eq(cell.as_ref(), before.as_ref()))
+                        .unwrap_or(false);
+
+                    if unchanged { continue }
+                    self.record(|| Change::Value(column_bit_mask, cell.clone_cell(), before, cell.clone_value()));
+
+                } // if ..
+
+                if let Some(column_bit_mask) = column_bit_mask { self.reindex_cell(column_bit_mask, cell.as_ref(), self.atomic_depth > 0usize); }
+
+            } // for ..
+        } // fn ..
+
+
+        fn record(&mut self, change: impl FnOnce() -> Change<B>) {
+            if self.changes.is_some() {
+
+                let change = change();
+                self.count_pending_changes(std::slice::from_ref(&change), true);
+                if let Some(changes) = self.changes.as_mut() { changes.push(change); }
+
+            } // if ..
+        } // fn ..
+
+
+        pub(crate) fn write_bit_mask(&mut self, entity: Entity, bit_mask: Option<B>) {
+
+            let before = match bit_mask {
+                Some(bit_mask) => self.entities.insert(entity, bit_mask),
+                None           => self.entities.remove(&entity),
+            }; // let ..
+
+            self.log_signature(entity);
+            self.record(|| Change::BitMask(entity, before, bit_mask));
+            self.record_entry(|_| JournalEntry::BitMask(entity, bit_mask));
+
+        } // fn ..
+
+
+        pub(crate) fn write_cell(
+            &mut self,
+            column_bit_mask: B,
+            entity:          Entity,
+            cell:            Option<Box<dyn ComponentCell>>,
+        ) {
+
+            self.record_entry(|world| world.journal_entry_of_cell(column_bit_mask, entity, cell.as_deref()));
+            let after  = self.changes.as_ref().and(cell.as_ref()).map(|cell| cell.clone_cell());
+
+            let held   = cell.as_ref().map(|cell| cell.cell_id());
+            let before = match self.component_columns.get_mut(&column_bit_mask) {
+                None                   => None,
+                Some(component_column) => match cell {
+                    Some(cell) => component_column.insert_cell(entity, cell),
+                    None       => component_column.remove_cell(entity),
+                }, // => ..
+            }; // let ..
+
+            self.hold_cell(before.as_ref().map(|cell| cell.cell_id()), held);
+
+            if before.is_some() || after.is_some() { self.record(|| Change::Cell(column_bit_mask, entity, before, after)); }
+            self.reindex(column_bit_mask, entity, true);
+
+        } // fn ..
+
+
+        pub(crate) fn write_parent(&mut self, entity: Entity, parent: Option<Entity>) {
+
+            let before = match parent {
+                Some(parent) => self.parents.insert(entity, parent),
+                None         => self.parents.remove(&entity),
+            }; // let ..
+
+            if before != parent {
+
+                self.record(|| Change::Parent(entity, before, parent));
+                self.record_entry(|_| JournalEntry::Parent(entity, parent));
+
+            } // if ..
+
+        } // fn ..
+
+
+        pub(crate) fn write_next_entity_id(&mut self, next_entity_id: EntityId) {
+
+            let before          = self.next_entity_id;
+            self.next_entity_id = next_entity_id;
+            self.record(|| Change::NextEntityId(before, next_entity_id));
+            self.record_entry(|_| JournalEntry::NextEntityId(next_entity_id));
+
+        } // fn ..
+
+
+        pub(crate) fn assign_value(
+            &mut self,
+            column_bit_mask: Option<B>,
+            cell:            &dyn ComponentCell,
+            value:           &dyn ComponentCell,
+        ) {
+
+            let before = self.changes.is_some().then(|| cell.clone_value());
+            cell.assign(value);
+
+            if let Some(column_bit_mask) = column_bit_mask { self.reindex_cell(column_bit_mask, cell, true); }
+
+            if let Some(before) = before { self.record(|| Change::Value(column_bit_mask, cell.clone_cell(), before, cell.clone_value())); }
+
+        } // fn ..
+
+
+        pub(crate) fn apply_change(&mut self, change: &Change<B>, forward: bool) {
+
+            match change {
+                Change::BitMask(entity, before, after) => {
+
+                    match pick(forward, before, after) {
+                        Some(bit_mask) => { self.entities.insert(*entity, *bit_mask); },
+                        None           => { self.entities.remove(entity); },
+                    } // match ..
+
+                    self.log_signature(*entity);
+
+                }, // => ..
+                Change::Cell(column_bit_mask, entity, before, after) => {
+
+                    if let Some(component_column) = self.component_columns.get_mut(column_bit_mask) {
+
+                        let held     = pick(forward, before, after).as_ref().map(|cell| cell.cell_id());
+                        let released = match pick(forward, before, after) {
+                            Some(cell) => component_column.insert_cell(*entity, cell.clone_cell()),
+                            None       => component_column.remove_cell(*entity),
+                        }; // let ..
+
+                        self.hold_cell(released.map(|cell| cell.cell_id()), held);
+
+                    } // if ..
+
+                    self.reindex(*column_bit_mask, *entity, false);
+
+                }, // => ..
+                Change::Value(column_bit_mask, cell, before, after) => {
+
+                    cell.assign(pick(forward, before, after).as_ref());
+                    if let Some(column_bit_mask) = column_bit_mask { self.reindex_cell(*column_bit_mask, cell.as_ref(), false); }
+
+                }, // => ..
+                Change::Parent(entity, before, after)  => match pick(forward, before, after) {
+                    Some(parent) => { self.parents.insert(*entity, *parent); },
+                    None         => { self.parents.remove(entity); },
+                }, // => ..
+                Change::NextEntityId(before, after)    => self.next_entity_id = *pick(forward, before, after),
+            } // match ..
+
+        } // fn ..
+
+
+        fn hold_cell(&mut self, released: Option<usize>, held: Option<usize>) {
+
+            if released == held { return }
+
+            if let Some(released) = released {
+                if let Some(holders) = self.cell_holders.get_mut(&released) {
+
+                    *holders -= 1usize;
+                    if *holders == 0usize { self.cell_holders.remove(&released); }
+
+                } // if ..
+            } // if ..
+
+            if let Some(held) = held { *self.cell_holders.entry(held).or_default() += 1usize; }
+
+        } // fn ..
+
+
+        pub(crate) fn revert_changes(&mut self, changes: &[Change<B>]) {
+            changes
+                .iter()
+                .rev()
+                .for_each(|change| self.apply_change(change, false));
+        } // fn ..
+    } // impl ..
+
+
+    fn pick<T>(forward: bool, before: T, after: T) -> T {
+        match forward {
+            true  => after,
+            false => before,
+        } // match ..
+    } // fn ..
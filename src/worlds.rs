@@ -2,19 +2,28 @@
 // D E P E N D E N C I E S
 //#########################
 
-    use std::collections::HashMap;
-    use std::any::TypeId;
+    use std::collections::{HashMap, VecDeque};
     use std::rc::Rc;
-    use std::cell::RefCell;
+    use std::cell::{Cell, RefCell};
     use std::ops::Range;
     use std::hash::Hash;
-    use std::fmt::{Debug, Display};
-    use std::fmt;
-    use std::error::Error;
+    use std::fmt::Debug;
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
-    use crate::components::{Component, ComponentCell, ComponentColumn};
+    use crate::errors::{EcsErr, ComponentType, BuildDiagnostic};
+    use crate::components::{Component, ComponentCell, ComponentColumn, ComponentInfo, ComponentDescription, Reflect, HashComponent};
     use crate::entities::{Entity, EntityBuilder, EntityId};
-    use crate::queries::QueryBuilder;
+    use crate::queries::{QueryBuilder, ColumnBorrow};
+    use crate::prefabs::Prefab;
+    use crate::scenes::{Scene, SceneComponent, SceneComponentLoader};
+    use crate::transactions::{Change, LentCells};
+    use crate::history::History;
+    use crate::journals::{Journal, JournalComponent, JournalComponentLoader};
+    use crate::snapshots::SnapshotBuffer;
+    use crate::indexes::{ComponentIndex, KeyIndex};
+    use crate::grids::{Position, SpatialIndex};
+    use crate::names::{self, Name};
 
     use rusty_toolkit::BitField;
 
@@ -23,91 +32,100 @@
 // D E F I N I T I O N S
 //#######################
 
+    type BuildLogger<F, P> = Box<dyn Fn(&BuildDiagnostic<F, P>)>;
+
+
+    static NEXT_WORLD_ID: AtomicUsize = AtomicUsize::new(0usize);
+
+
     pub struct World<B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        components:         Vec<TypeId>,
-        flags:              HashMap<F, Range<u8>>,
-        component_columns:  HashMap<B, Box<dyn ComponentColumn>>,
-        component_pointers: HashMap<P, Box<dyn ComponentCell>>,
-        entities:           HashMap<Entity, B>,
-        next_entity_id:     EntityId,
+        pub(crate) id:                      usize,
+        pub(crate) components:              Vec<ComponentInfo>,
+        pub(crate) flags:                   HashMap<F, Range<u8>>,
+        pub(crate) component_columns:       HashMap<B, Box<dyn ComponentColumn>>,
+        pub(crate) component_pointers:      HashMap<P, Box<dyn ComponentCell>>,
+        pub(crate) scene_components:        HashMap<String, SceneComponentLoader<B, F, P>>,
+        pub(crate) entities:                HashMap<Entity, B>,
+        pub(crate) parents:                 HashMap<Entity, Entity>,
+        pub(crate) next_entity_id:          EntityId,
+        pub(crate) debug_validation:        bool,
+        pub(crate) changes:                 Option<Vec<Change<B>>>,
+        pub(crate) history:                 Option<History<B>>,
+        pub(crate) journal_components:      HashMap<String, JournalComponentLoader>,
+        pub(crate) journal:                 Option<Journal<B, P>>,
+        pub(crate) deterministic_iteration: bool,
+        pub(crate) snapshots:               Option<SnapshotBuffer<B>>,
+        pub(crate) column_borrows:          Rc<RefCell<HashMap<B, ColumnBorrow>>>,
+        pub(crate) next_query_id:           Cell<usize>,
+        pub(crate) signature_log:           VecDeque<Entity>,
+        pub(crate) signature_log_start:     usize,
+        pub(crate) indexes:                 HashMap<ComponentType, Vec<Box<dyn ComponentIndex>>>,
+        pub(crate) index_conflict:          Option<EcsErr<B, F, P>>,
+        pub(crate) atomic_depth:            usize,
+        pub(crate) lent_cells:              RefCell<LentCells<B>>,
+        pub(crate) cell_holders:            HashMap<usize, usize>,
     } // struct ..
 
 
     pub struct WorldBuilder<B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        components:         Vec<TypeId>,
-        flags:              HashMap<F, Range<u8>>,
-        component_count:    usize,
-        component_columns:  HashMap<B, Box<dyn ComponentColumn>>,
-        component_pointers: HashMap<P, Box<dyn ComponentCell>>,
+        components:              Vec<ComponentInfo>,
+        flags:                   HashMap<F, Range<u8>>,
+        flag_widths:             Vec<(F, u8)>,
+        component_count:         usize,
+        component_columns:       HashMap<B, Box<dyn ComponentColumn>>,
+        component_pointers:      HashMap<P, Box<dyn ComponentCell>>,
+        scene_components:        HashMap<String, SceneComponentLoader<B, F, P>>,
+        diagnostics:             Vec<BuildDiagnostic<F, P>>,
+        strict:                  bool,
+        logger:                  Option<BuildLogger<F, P>>,
+        debug_validation:        bool,
+        history_budget:          Option<usize>,
+        journal_components:      HashMap<String, JournalComponentLoader>,
+        journal:                 Option<Journal<B, P>>,
+        deterministic_iteration: bool,
+        snapshot_capacity:       Option<usize>,
+        indexes:                 HashMap<ComponentType, Vec<Box<dyn ComponentIndex>>>,
     } // struct ..
 
 
-    #[derive(Debug)]
-    pub enum EcsErr<B: BitField, F: BitField, P> {
-        MissingEntity(Entity),
-        MissingComponent(TypeId),
-        MissingComponentPtr(P),
-        MissingFlag(F),
-        MissingComponentToEntity(TypeId, Entity),
-        FailedToDowncastComponentCol(B),
-        FailedToDowncastPtr(P),
-        BitFieldRangeTooSmall(usize, usize),
-    } // enum ..
-    
-
 //###############################
 // I M P L E M E N T A T I O N S
 //###############################
 
-    impl<B: BitField + Debug, F: BitField + Debug, P: Debug> Error for EcsErr<B, F, P> {}
-    impl<B: BitField, F: BitField + Debug, P: Debug> Display for EcsErr<B, F, P> {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}", match self {
-                EcsErr::MissingEntity(e)                => format!("The entity `{:?}` is not registered!", e),
-                EcsErr::MissingComponent(c)             => format!("The component `{:?}` is not registered!", c),
-                EcsErr::MissingComponentPtr(p)          => format!("The component pointer `{:?}` is not registered!", p),
-                EcsErr::MissingFlag(b)                  => format!("The flag `{:x}` is not registerd!", b),
-                EcsErr::FailedToDowncastComponentCol(b) => format!("Failed to downcast the `{:x}` component column!", b),
-                EcsErr::FailedToDowncastPtr(p)          => format!("Failed to downcast the `{:?}` component pointer!", p),
-                EcsErr::MissingComponentToEntity(c, e)  => format!("The entity `{:?}` has no registered component `{:?}`!", e, c),
-                EcsErr::BitFieldRangeTooSmall(s, r)     => format!("The bitfield's range is too low: `{} > {}`!", s, r),
-            }) // write()
-        } // fn ..
-    } // impl ..
-
-
     impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
         pub fn builder() -> WorldBuilder<B, F, P> {
             WorldBuilder {
-                components:         Vec::default(),
-                flags:              HashMap::default(),
-                component_count:    0usize,
-                component_columns:  HashMap::default(),
-                component_pointers: HashMap::default(),
+                components:              Vec::default(),
+                flags:                   HashMap::default(),
+                flag_widths:             Vec::default(),
+                component_count:         0usize,
+                component_columns:       HashMap::default(),
+                component_pointers:      HashMap::default(),
+                scene_components:        HashMap::default(),
+                diagnostics:             Vec::default(),
+                strict:                  false,
+                logger:                  None,
+                debug_validation:        false,
+                history_budget:          None,
+                journal_components:      HashMap::default(),
+                journal:                 None,
+                deterministic_iteration: false,
+                snapshot_capacity:       None,
+                indexes:                 HashMap::default(),
             } // WorldBuilder
         } // fn ..
 
 
-        fn get_mut_raw_component_column<C: Component>(&mut self) -> Result<&mut Box<dyn ComponentColumn>, EcsErr<B, F, P>> {
-
-            let bit_mask = self.component_bit_mask::<C>()?;
-            match self.component_columns.get_mut(&bit_mask) {
-                None                   => Err(EcsErr::MissingComponent(TypeId::of::<C>())),
-                Some(component_column) => Ok(component_column),
-            } // match ..
-        } // fn ..
-
-
         pub(crate) fn get_component_column<C: Component>(&self) -> Result<&HashMap<Entity, Rc<RefCell<C>>>, EcsErr<B, F, P>> {
 
             let bit_mask = self.component_bit_mask::<C>()?;
             match self.component_columns.get(&bit_mask) {
-                None                   => Err(EcsErr::MissingComponent(TypeId::of::<C>())),
+                None                   => Err(EcsErr::MissingComponent(ComponentType::of::<C>())),
                 Some(component_column) => match component_column
                     .as_any()
                     .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>() {
 
-                        None                    => Err(EcsErr::FailedToDowncastComponentCol(bit_mask)),
+                        None                    => Err(EcsErr::FailedToDowncastComponentCol(ComponentType::of::<C>(), bit_mask)),
                         Some(downcasted_column) => Ok(downcasted_column),
 
                     } // match ..
@@ -115,64 +133,97 @@
         } // fn ..
 
 
-        fn get_mut_component_column<C: Component>(&mut self) -> Result<&mut HashMap<Entity, Rc<RefCell<C>>>, EcsErr<B, F, P>> {
+        pub fn get_pointer_component<C: Component>(
+            &self,
+            id: P,
+        ) -> Result<&Rc<RefCell<C>>, EcsErr<B, F, P>> {
+            self.during("get_pointer_component", |world| {
 
-            let bit_mask = self.component_bit_mask::<C>()?;
-            match self.component_columns.get_mut(&bit_mask) {
-                None                   => Err(EcsErr::MissingComponent(TypeId::of::<C>())),
-                Some(component_column) => match component_column
-                    .as_any_mut()
-                    .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>() {
+                match world.component_pointers.get(&id) {
+                    None          => Err(EcsErr::MissingComponentPtr(id)),
+                    Some(raw_ptr) => match raw_ptr.as_any().downcast_ref::<Rc<RefCell<C>>>() {
+                        Some(ptr) => {
 
-                        None                    => Err(EcsErr::FailedToDowncastComponentCol(bit_mask)),
-                        Some(downcasted_column) => Ok(downcasted_column),
+                            world.lend_cell(None, ptr);
+                            Ok(ptr)
 
+                        }, // => ..
+                        None      => Err(EcsErr::FailedToDowncastPtr(id, ComponentType::of::<C>()))
                     } // match ..
                 } // match ..
-        } // fn ..
 
-
-        pub fn get_pointer_component<C: Component>(
-            &self,
-            id: P,
-        ) -> Result<&Rc<RefCell<C>>, EcsErr<B, F, P>> {
-            match self.component_pointers.get(&id) {
-                None          => Err(EcsErr::MissingComponentPtr(id)),
-                Some(raw_ptr) => match raw_ptr.as_any().downcast_ref::<Rc<RefCell<C>>>() {
-                    Some(ptr) => Ok(ptr),
-                    None      => Err(EcsErr::FailedToDowncastPtr(id))
-                } // match ..
-            } // match ..
+            }) // during()
         } // fn ..
 
 
-        pub(crate) fn get_entities(&self, bit_mask_filter: B) -> Vec<Entity> {
-            self.entities
+        pub(crate) fn get_entities(&self, bit_mask_filter: B, include_disabled: bool) -> Vec<Entity> {
+            self.ordered_entities(self.entities
                 .iter()
-                .filter(|(_, bit_mask)| bit_mask.has_bits(bit_mask_filter))
+                .filter(|(_, bit_mask)| self.matches_bit_mask(**bit_mask, bit_mask_filter, include_disabled))
                 .map(|(entity, _)| *entity)
                 .collect()
+            ) // ordered_entities()
+        } // fn ..
+
+
+        pub(crate) fn matches_bit_mask(&self, bit_mask: B, bit_mask_filter: B, include_disabled: bool) -> bool {
+            bit_mask.has_bits(bit_mask_filter) && (include_disabled || !bit_mask.has_bits(self.disabled_bit_mask()))
+        } // fn ..
+
+
+        pub(crate) fn disabled_bit_mask(&self) -> B { B::nth_bit((usize::from(B::BITS) - 1usize) as u8) }
+
+
+        pub(crate) fn is_disabled(&self, entity: Entity) -> bool {
+            self.entities
+                .get(&entity)
+                .is_some_and(|bit_mask| bit_mask.has_bits(self.disabled_bit_mask()))
+        } // fn ..
+
+
+        pub(crate) fn ordered_entities(&self, mut entities: Vec<Entity>) -> Vec<Entity> {
+
+            if self.deterministic_iteration { entities.sort_unstable(); }
+            entities
+
         } // fn ..
 
 
         pub(crate) fn component_bit_mask<C: Component>(&self) -> Result<B, EcsErr<B, F, P>> {
 
-            let type_id = TypeId::of::<C>();
+            let kind = ComponentType::of::<C>();
             match self.components
                 .iter()
                 .enumerate()
-                .find_map(|(index, id)| {
-                    match id == &type_id {
+                .find_map(|(index, info)| {
+                    match info.kind == kind {
                         true  => Some(B::nth_bit(index as u8)),
                         false => None,
                     } // match ..
                 }) {
                     Some(bit_mask) => Ok(bit_mask),
-                    None           => Err(EcsErr::MissingComponent(type_id))
+                    None           => Err(EcsErr::MissingComponent(ComponentType::of::<C>()))
                 } // match ..
         } // fn ..
 
 
+        pub(crate) fn column_bit_mask(&self, kind: ComponentType) -> Option<B> {
+            self.components
+                .iter()
+                .position(|info| info.kind == kind)
+                .map(|index| B::nth_bit(index as u8))
+        } // fn ..
+
+
+        pub(crate) fn column_info(&self, column_bit_mask: B) -> Option<&ComponentInfo> {
+            self.components
+                .iter()
+                .enumerate()
+                .find(|(index, _)| B::nth_bit(*index as u8) == column_bit_mask)
+                .map(|(_, info)| info)
+        } // fn ..
+
+
         pub(crate) fn flag_bit_mask(
             &self,
             flag:    F,
@@ -186,7 +237,7 @@
                         true => Some(
                                 match variant {
                                 Some(variant) => ((variant << range.start) & B::range_bit_mask(range.clone())) << self.components.len() as u8,
-                                None          => B::range_bit_mask(range.clone()),
+                                None          => B::range_bit_mask(range.clone()) << self.components.len() as u8,
                             } // match ..
                         ), // => ..
                         false => None,
@@ -198,7 +249,7 @@
         } // fn ..
 
 
-        fn get_entity_bit_mask(&self, entity: Entity) -> Result<B, EcsErr<B, F, P>> {
+        pub(crate) fn get_entity_bit_mask(&self, entity: Entity) -> Result<B, EcsErr<B, F, P>> {
             match self.entities.get(&entity) {
                 Some(bit_mask) => Ok(*bit_mask),
                 None           => Err(EcsErr::MissingEntity(entity)),
@@ -206,14 +257,57 @@
         } // fn ..
 
 
-        fn get_mut_entity_bit_mask(&mut self, entity: Entity) -> Result<&mut B, EcsErr<B, F, P>> {
-            match self.entities.get_mut(&entity) {
-                Some(bit_mask) => Ok(bit_mask),
-                None           => Err(EcsErr::MissingEntity(entity)),
+        fn check_entity_group(&self, entity_group: &[Entity]) -> Result<(), EcsErr<B, F, P>> {
+
+            let missing_entities = entity_group
+                .iter()
+                .filter(|entity| !self.entities.contains_key(entity))
+                .copied()
+                .collect::<Vec<Entity>>();
+
+            match missing_entities.is_empty() {
+                true  => Ok(()),
+                false => Err(EcsErr::MissingEntityGroup(missing_entities)),
             } // match ..
         } // fn ..
 
 
+        fn set_entity_bits(&mut self, entity: Entity, bit_mask: B) -> Result<(), EcsErr<B, F, P>> {
+
+            let mut entity_bit_mask = self.get_entity_bit_mask(entity)?;
+            entity_bit_mask.set_bits(bit_mask);
+
+            self.write_bit_mask(entity, Some(entity_bit_mask));
+            Ok(())
+
+        } // fn ..
+
+
+        fn unset_entity_bits(&mut self, entity: Entity, bit_mask: B) -> Result<(), EcsErr<B, F, P>> {
+
+            let mut entity_bit_mask = self.get_entity_bit_mask(entity)?;
+            entity_bit_mask.unset_bits(bit_mask);
+
+            self.write_bit_mask(entity, Some(entity_bit_mask));
+            Ok(())
+
+        } // fn ..
+
+
+        fn set_entity_group_bits(&mut self, entity_group: &[Entity], bit_mask: B) -> Result<(), EcsErr<B, F, P>> {
+            entity_group
+                .iter()
+                .try_for_each(|entity| self.set_entity_bits(*entity, bit_mask))
+        } // fn ..
+
+
+        fn unset_entity_group_bits(&mut self, entity_group: &[Entity], bit_mask: B) -> Result<(), EcsErr<B, F, P>> {
+            entity_group
+                .iter()
+                .try_for_each(|entity| self.unset_entity_bits(*entity, bit_mask))
+        } // fn ..
+
+
         pub(crate) fn add_component_to_entity_builder<C: Component>(
             &mut self,
             component:       C,
@@ -221,9 +315,15 @@
             entity_bit_mask: &mut B,
         ) -> Result<(), EcsErr<B, F, P>> {
 
-            entity_bit_mask.set_bits(self.component_bit_mask::<C>()?);
+            let bit_mask = self.component_bit_mask::<C>()?;
+            self.checking_indexes(|world| {
+
+                world.write_cell(bit_mask, entity, Some(Box::new(Rc::new(RefCell::new(component)))));
+                Ok(())
 
-            self.get_mut_component_column::<C>()?.insert(entity, Rc::new(RefCell::new(component)));
+            })?; // checking_indexes()
+
+            entity_bit_mask.set_bits(bit_mask);
             Ok(())
 
         } // fn ..
@@ -235,10 +335,16 @@
             entity:          Entity,
             entity_bit_mask: &mut B,
         ) -> Result<(), EcsErr<B, F, P>> {
-            
-            entity_bit_mask.set_bits(self.component_bit_mask::<C>()?);
 
-            self.get_mut_component_column::<C>()?.insert(entity, component.clone());
+            let bit_mask = self.component_bit_mask::<C>()?;
+            self.checking_indexes(|world| {
+
+                world.write_cell(bit_mask, entity, Some(Box::new(component.clone())));
+                Ok(())
+
+            })?; // checking_indexes()
+
+            entity_bit_mask.set_bits(bit_mask);
             Ok(())
 
         } // fn ..
@@ -261,7 +367,9 @@
             &self,
             entity: Entity,
         ) -> Result<bool, EcsErr<B, F, P>> {
-            Ok(self.get_entity_bit_mask(entity)?.has_bits(self.component_bit_mask::<C>()?))
+            self.during("entity_has_component", |world| {
+                Ok(world.get_entity_bit_mask(entity)?.has_bits(world.component_bit_mask::<C>()?))
+            }) // during()
         } // fn ..
 
 
@@ -269,14 +377,16 @@
             &self,
             entity_group: &[Entity],
         ) -> Result<Vec<bool>, EcsErr<B, F, P>> {
+            self.during("entity_group_has_component", |world| {
 
-            let bit_mask = self.component_bit_mask::<C>()?;
-            entity_group.iter()
-                .map(|entity| match self.get_entity_bit_mask(*entity) {
-                    Ok(entity_bit_mask) => Ok(entity_bit_mask.has_bits(bit_mask)),
-                    Err(err)            => Err(err),
-                }).collect()
+                let bit_mask = world.component_bit_mask::<C>()?;
+                entity_group.iter()
+                    .map(|entity| match world.get_entity_bit_mask(*entity) {
+                        Ok(entity_bit_mask) => Ok(entity_bit_mask.has_bits(bit_mask)),
+                        Err(err)            => Err(err),
+                    }).collect()
 
+            }) // during()
         } // fn ..
 
 
@@ -286,7 +396,9 @@
             flag:    F,
             variant: Option<B>,
         ) ->Result<bool, EcsErr<B, F, P>> {
-            Ok(self.get_entity_bit_mask(entity)?.has_bits(self.flag_bit_mask(flag, variant)?))
+            self.during("entity_has_flag", |world| {
+                Ok(world.get_entity_bit_mask(entity)?.has_bits(world.flag_bit_mask(flag, variant)?))
+            }) // during()
         } // fn ..
 
 
@@ -296,14 +408,16 @@
             flag:         F,
             variant:      Option<B>,
         ) -> Result<Vec<bool>, EcsErr<B, F, P>> {
+            self.during("entity_group_has_flag", |world| {
 
-            let bit_mask = self.flag_bit_mask(flag, variant)?;
-            entity_group.iter()
-                .map(|entity| match self.get_entity_bit_mask(*entity) {
-                    Ok(entity_bit_mask) => Ok(entity_bit_mask.has_bits(bit_mask)),
-                    Err(err)            => Err(err),
-                }).collect()
+                let bit_mask = world.flag_bit_mask(flag, variant)?;
+                entity_group.iter()
+                    .map(|entity| match world.get_entity_bit_mask(*entity) {
+                        Ok(entity_bit_mask) => Ok(entity_bit_mask.has_bits(bit_mask)),
+                        Err(err)            => Err(err),
+                    }).collect()
 
+            }) // during()
         } // fn ..
 
 
@@ -312,12 +426,15 @@
             component: C,
             entity:    Entity,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("add_component_to_entity", |world| {
 
-            self.get_entity_bit_mask(entity)?.set_bits(self.component_bit_mask::<C>()?);
-            self.get_mut_component_column::<C>()?.insert(entity, Rc::new(RefCell::new(component)));
+                let bit_mask = world.component_bit_mask::<C>()?;
+                world.set_entity_bits(entity, bit_mask)?;
+                world.write_cell(bit_mask, entity, Some(Box::new(Rc::new(RefCell::new(component)))));
 
-            Ok(())
+                Ok(())
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -326,25 +443,18 @@
             component:    C,
             entity_group: &[Entity],
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("add_component_to_entity_group", |world| {
 
-            let bit_mask             = self.component_bit_mask::<C>()?;
-            let mut component_column = self.get_mut_component_column::<C>()?.clone();
-
-            entity_group
-                .iter()
-                .map(|entity| {
-
-                    component_column.insert(*entity, Rc::new(RefCell::new(component.clone())));
-                    
-                    match self.get_mut_entity_bit_mask(*entity) {
-                        Ok(entity_bit_mask) => { entity_bit_mask.set_bits(bit_mask); Ok(()) },
-                        Err(err)            => Err(err),
-                    } // match ..
-                }).collect::<Result<_, EcsErr<B, F, P>>>()?; // map()
+                world.check_entity_group(entity_group)?;
+                let bit_mask = world.component_bit_mask::<C>()?;
 
+                for entity in entity_group.iter() {
+                    world.write_cell(bit_mask, *entity, Some(Box::new(Rc::new(RefCell::new(component.clone())))));
+                } // for ..
 
-            Ok(())
+                world.set_entity_group_bits(entity_group, bit_mask)
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -353,12 +463,15 @@
             component: &Rc<RefCell<C>>,
             entity:    Entity,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("add_shared_component_to_entity", |world| {
 
-            self.get_entity_bit_mask(entity)?.set_bits(self.component_bit_mask::<C>()?);
-            self.get_mut_component_column::<C>()?.insert(entity, component.clone());
+                let bit_mask = world.component_bit_mask::<C>()?;
+                world.set_entity_bits(entity, bit_mask)?;
+                world.write_cell(bit_mask, entity, Some(Box::new(component.clone())));
 
-            Ok(())
+                Ok(())
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -367,24 +480,18 @@
             component:    &Rc<RefCell<C>>,
             entity_group: &[Entity],
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("add_shared_component_to_entity_group", |world| {
 
-            let bit_mask             = self.component_bit_mask::<C>()?;
-            let mut component_column = self.get_mut_component_column::<C>()?.clone();
+                world.check_entity_group(entity_group)?;
+                let bit_mask = world.component_bit_mask::<C>()?;
 
-            entity_group.iter()
-                .map(|entity| {
+                for entity in entity_group.iter() {
+                    world.write_cell(bit_mask, *entity, Some(Box::new(component.clone())));
+                } // for ..
 
-                    component_column.insert(*entity, component.clone());
-                    
-                    match self.get_mut_entity_bit_mask(*entity) {
-                        Ok(entity_bit_mask) => { entity_bit_mask.set_bits(bit_mask); Ok(()) },
-                        Err(err)            => Err(err),
-                    } // match ..
-                }).collect::<Result<_, EcsErr<B, F, P>>>()?; // map()
-
-
-            Ok(())
+                world.set_entity_group_bits(entity_group, bit_mask)
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -392,7 +499,15 @@
             &self,
             entity: Entity,
         ) -> Result<Option<&Rc<RefCell<C>>>, EcsErr<B, F, P>> {
-            Ok(self.get_component_column::<C>()?.get(&entity))
+            self.during("get_entity_component", |world| {
+
+                let bit_mask  = world.component_bit_mask::<C>()?;
+                let component = world.get_component_column::<C>()?.get(&entity);
+
+                if let Some(component) = component { world.lend_cell(Some(bit_mask), component); }
+                Ok(component)
+
+            }) // during()
         } // fn ..
 
 
@@ -400,14 +515,18 @@
             &self,
             entity_group: &[Entity],
         ) -> Result<Vec<Option<&Rc<RefCell<C>>>>, EcsErr<B, F, P>> {
+            self.during("get_some_entity_group_component", |world| {
 
-            let component_column = self.get_component_column::<C>()?;
+                let bit_mask         = world.component_bit_mask::<C>()?;
+                let component_column = world.get_component_column::<C>()?;
 
-            Ok(entity_group
-                .iter()
-                .map(|entity| component_column.get(entity))
-                .collect())
+                Ok(entity_group
+                    .iter()
+                    .map(|entity| component_column.get(entity).filter(|_| !world.is_disabled(*entity)))
+                    .inspect(|component| if let Some(component) = component { world.lend_cell(Some(bit_mask), *component); })
+                    .collect())
 
+            }) // during()
         } // fn ..
 
 
@@ -415,15 +534,25 @@
             &self,
             entity_group: &[Entity],
         ) -> Result<Vec<&Rc<RefCell<C>>>, EcsErr<B, F, P>> {
+            self.during("get_entity_group_component", |world| {
 
-            let component_column = self.get_component_column::<C>()?;
+                let bit_mask         = world.component_bit_mask::<C>()?;
+                let component_column = world.get_component_column::<C>()?;
 
-            entity_group
-                .iter()
-                .map(|entity| match component_column.get(entity) {
-                    Some(component) => Ok(component),
-                    None            => Err(EcsErr::MissingComponentToEntity(TypeId::of::<C>(), *entity))
-                }).collect()
+                entity_group
+                    .iter()
+                    .filter(|entity| !world.is_disabled(**entity))
+                    .map(|entity| match component_column.get(entity) {
+                        Some(component) => {
+
+                            world.lend_cell(Some(bit_mask), component);
+                            Ok(component)
+
+                        }, // => ..
+                        None            => Err(EcsErr::MissingComponentToEntity(ComponentType::of::<C>(), *entity))
+                    }).collect()
+
+            }) // during()
         } // fn ..
 
 
@@ -431,13 +560,15 @@
             &mut self,
             entity: Entity,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("delete_entity_component", |world| {
 
-            let bit_mask = self.component_bit_mask::<C>()?;
-            self.get_mut_entity_bit_mask(entity)?.unset_bits(bit_mask);
-            self.get_mut_raw_component_column::<C>()?.remove_entity(entity);
+                let bit_mask = world.component_bit_mask::<C>()?;
+                world.unset_entity_bits(entity, bit_mask)?;
+                world.write_cell(bit_mask, entity, None);
 
-            Ok(())
+                Ok(())
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -445,22 +576,43 @@
             &mut self,
             entity_group: &[Entity],
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("delete_entity_group_component", |world| {
 
-            let bit_mask = self.component_bit_mask::<C>()?;
+                world.check_entity_group(entity_group)?;
+                let bit_mask = world.component_bit_mask::<C>()?;
 
-            entity_group
-                .iter()
-                .map(|entity| {
+                for entity in entity_group.iter() {
+                    world.write_cell(bit_mask, *entity, None);
+                } // for ..
 
-                    self.get_mut_raw_component_column::<C>()?.remove_entity(*entity);
-                    match self.get_mut_entity_bit_mask(*entity) {
-                        Ok(entity_bit_mask) => { entity_bit_mask.unset_bits(bit_mask); Ok(()) },
-                        Err(err)            => Err(err),
-                    } // match ..
-                }).collect::<Result<_, EcsErr<B, F, P>>>()?;
+                world.unset_entity_group_bits(entity_group, bit_mask)
 
-            Ok(())
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn disable_entity(&mut self, entity: Entity) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("disable_entity", |world| {
 
+                let bit_mask = world.disabled_bit_mask();
+                world.set_entity_bits(entity, bit_mask)
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn enable_entity(&mut self, entity: Entity) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("enable_entity", |world| {
+
+                let bit_mask = world.disabled_bit_mask();
+                world.unset_entity_bits(entity, bit_mask)
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn is_entity_disabled(&self, entity: Entity) -> Result<bool, EcsErr<B, F, P>> {
+            self.during("is_entity_disabled", |world| Ok(world.get_entity_bit_mask(entity)?.has_bits(world.disabled_bit_mask())))
         } // fn ..
 
 
@@ -470,11 +622,12 @@
             flag:    F,
             variant: Option<B>,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("set_entity_flag", |world| {
 
-            let bit_mask = self.flag_bit_mask(flag, variant)?;
-            self.get_mut_entity_bit_mask(entity)?.set_bits(bit_mask);
-            Ok(())
+                let bit_mask = world.flag_bit_mask(flag, variant)?;
+                world.set_entity_bits(entity, bit_mask)
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -484,20 +637,14 @@
             flag:         F,
             variant:      Option<B>,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("set_entity_group_flag", |world| {
 
-            let bit_mask = self.flag_bit_mask(flag, variant)?;
+                world.check_entity_group(entity_group)?;
+                let bit_mask = world.flag_bit_mask(flag, variant)?;
 
-            entity_group
-                .iter()
-                .map(|entity|
-                    match self.get_mut_entity_bit_mask(*entity) {
-                        Ok(entity_bit_mask) => { entity_bit_mask.set_bits(bit_mask); Ok(()) },
-                        Err(err)            => Err(err),
-                    } // match ..
-                ).collect::<Result<_, EcsErr<B, F, P>>>()?;
-
-            Ok(())
+                world.set_entity_group_bits(entity_group, bit_mask)
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -507,11 +654,12 @@
             flag:    F,
             variant: Option<B>,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("remove_entity_flag", |world| {
 
-            let bit_mask = self.flag_bit_mask(flag, variant)?;
-            self.get_mut_entity_bit_mask(entity)?.unset_bits(bit_mask);
-            Ok(())
+                let bit_mask = world.flag_bit_mask(flag, variant)?;
+                world.unset_entity_bits(entity, bit_mask)
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -521,20 +669,14 @@
             flag:         F,
             variant:      Option<B>,
         ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("remove_entity_group_flag", |world| {
 
-            let bit_mask = self.flag_bit_mask(flag, variant)?;
+                world.check_entity_group(entity_group)?;
+                let bit_mask = world.flag_bit_mask(flag, variant)?;
 
-            entity_group
-                .iter()
-                .map(|entity|
-                    match self.get_mut_entity_bit_mask(*entity) {
-                        Ok(entity_bit_mask) => { entity_bit_mask.unset_bits(bit_mask); Ok(()) },
-                        Err(err)            => Err(err),
-                    } // match ..
-                ).collect::<Result<_, EcsErr<B, F, P>>>()?;
-
-            Ok(())
+                world.unset_entity_group_bits(entity_group, bit_mask)
 
+            }) // during_mut()
         } // fn ..
 
 
@@ -542,55 +684,214 @@
             &mut self,
             entity:          Entity,
             entity_bit_mask: B,
-        ) { self.entities.insert(entity, entity_bit_mask); }
-
+        ) { self.write_bit_mask(entity, Some(entity_bit_mask)); }
 
 
         pub fn delete_entity(&mut self, entity: Entity) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("delete_entity", |world| {
 
-            let entity_bit_mask = self.get_entity_bit_mask(entity)?;
-            self.component_columns
-                .iter_mut()
-                .map(|(bit_mask, component_column)| if entity_bit_mask.has_bits(*bit_mask) {
+                world.get_entity_bit_mask(entity)?;
+                world.despawn_entity(entity);
+                Ok(())
 
-                        component_column.remove_entity(entity);
-                        Ok(())
+            }) // during_mut()
+        } // fn ..
 
-                    } else { Err(EcsErr::MissingEntity(entity)) }
-                ).collect::<Result<_, EcsErr<B, F, P>>>()?;
-            
-            self.entities.remove(&entity);
 
-            Ok(())
-            
+        pub(crate) fn despawn_entity(&mut self, entity: Entity) {
+
+            let entity_bit_mask  = self.entities.get(&entity).copied().unwrap_or(B::MIN);
+            let column_bit_masks = (0..self.components.len())
+                .map(|index| B::nth_bit(index as u8))
+                .filter(|bit_mask| entity_bit_mask.has_bits(*bit_mask))
+                .collect::<Vec<B>>();
+
+            for bit_mask in column_bit_masks { self.write_cell(bit_mask, entity, None); }
+            for child in self.get_entity_children(entity) { self.write_parent(child, None); }
+
+            self.write_parent(entity, None);
+            self.write_bit_mask(entity, None);
+
         } // fn ..
 
 
         pub fn delete_entity_group(&mut self, entity_group: &[Entity]) -> Result<(), EcsErr<B, F, P>> {
-            entity_group.iter().map(|entity| self.delete_entity(*entity)).collect::<Result<_, EcsErr<B, F, P>>>()?;
-            Ok(())
+            self.during_mut("delete_entity_group", |world| {
+
+                world.check_entity_group(entity_group)?;
+                for entity in entity_group.iter() {
+                    if world.entities.contains_key(entity) { world.despawn_entity(*entity); }
+                } // for ..
+
+                Ok(())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub(crate) fn link_entity_parent(
+            &mut self,
+            entity: Entity,
+            parent: Entity,
+        ) { self.write_parent(entity, Some(parent)); }
+
+
+        pub fn set_entity_parent(
+            &mut self,
+            entity: Entity,
+            parent: Entity,
+        ) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("set_entity_parent", |world| {
+
+                world.get_entity_bit_mask(entity)?;
+                world.get_entity_bit_mask(parent)?;
+
+                let mut ancestor = Some(parent);
+                while let Some(current) = ancestor {
+                    match current == entity {
+                        true  => return Err(EcsErr::CyclicParent(entity, parent)),
+                        false => ancestor = world.get_entity_parent(current),
+                    } // match ..
+                } // while ..
+
+                world.link_entity_parent(entity, parent);
+                Ok(())
+
+            }) // during_mut()
         } // fn ..
 
 
+        pub fn remove_entity_parent(&mut self, entity: Entity) -> Result<(), EcsErr<B, F, P>> {
+            self.during_mut("remove_entity_parent", |world| {
+
+                world.get_entity_bit_mask(entity)?;
+                world.write_parent(entity, None);
+                Ok(())
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn get_entity_parent(&self, entity: Entity) -> Option<Entity> { self.parents.get(&entity).copied() }
+
+
+        pub fn get_entity_children(&self, entity: Entity) -> Vec<Entity> {
+            self.ordered_entities(self.parents
+                .iter()
+                .filter(|(_, parent)| **parent == entity)
+                .map(|(child, _)| *child)
+                .collect()
+            ) // ordered_entities()
+        } // fn ..
+
+
+        pub fn spawn_prefab(&mut self, prefab: &Prefab<B, F, P>) -> Result<Entity, EcsErr<B, F, P>> {
+            self.during_mut("spawn_prefab", |world| {
+                world.atomically(|world| Ok(world.new_entity().with_prefab(prefab)?.build()))
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn entity_components(&self, entity: Entity) -> Result<Vec<ComponentDescription>, EcsErr<B, F, P>> {
+            self.during("entity_components", |world| {
+
+                let entity_bit_mask = world.get_entity_bit_mask(entity)?;
+                Ok(world.components
+                    .iter()
+                    .enumerate()
+                    .map(|(index, info)| (B::nth_bit(index as u8), info))
+                    .filter(|(bit_mask, _)| entity_bit_mask.has_bits(*bit_mask))
+                    .map(|(bit_mask, info)| info.describe(world.component_columns
+                        .get(&bit_mask)
+                        .and_then(|component_column| component_column.get_cell(entity))
+                    )).collect())
+
+            }) // during()
+        } // fn ..
+
+
+        pub(crate) fn during<'world, R>(
+            &'world self,
+            operation: &'static str,
+            f:         impl FnOnce(&'world Self) -> Result<R, EcsErr<B, F, P>>,
+        ) -> Result<R, EcsErr<B, F, P>> { f(self).map_err(|err| self.named(err.during(operation))) }
+
+
+        pub(crate) fn during_mut<R>(
+            &mut self,
+            operation: &'static str,
+            f:         impl FnOnce(&mut Self) -> Result<R, EcsErr<B, F, P>>,
+        ) -> Result<R, EcsErr<B, F, P>> {
+
+            self.settle_lent_cells();
+
+            let result = self.checking_indexes(f).map_err(|err| self.named(err.during(operation)));
+            self.enforce_history_budget();
+            self.debug_validate(operation);
+            result
+
+        } // fn ..
+
+
+        pub(crate) fn all_components_bit_mask(&self) -> B {
+            (0..self.components.len()).fold(B::MIN, |bit_mask, index| bit_mask | B::nth_bit(index as u8))
+        } // fn ..
+
+
+        pub(crate) fn registered_bit_mask(&self) -> B {
+            self.flags
+                .values()
+                .fold(self.all_components_bit_mask() | self.disabled_bit_mask(), |bit_mask, range| bit_mask | (B::range_bit_mask(range.clone()) << self.components.len() as u8))
+        } // fn ..
+
+
+        pub(crate) fn scene_component_loader(&self, name: &str) -> Option<&SceneComponentLoader<B, F, P>> { self.scene_components.get(name) }
+
+
+        pub fn load_scene(&mut self, source: &str) -> Result<Scene<B, F>, EcsErr<B, F, P>>
+        where B: FromStr, F: FromStr {
+
+            self.during_mut("load_scene", |world| {
+
+                let mut scene = Scene::default();
+                scene.reload(world, source)?;
+                Ok(scene)
+
+            }) // during_mut()
+        } // fn ..
+
+
+        pub fn reload_scene(&mut self, scene: &mut Scene<B, F>, source: &str) -> Result<(), EcsErr<B, F, P>>
+        where B: FromStr, F: FromStr { self.during_mut("reload_scene", |world| scene.reload(world, source)) }
+
+
         pub fn new_entity(&mut self) -> EntityBuilder<B, F, P> {
 
-            self.next_entity_id += 1;
-            EntityBuilder::new(self.next_entity_id - 1, self)
+            let id = self.next_entity_id;
+            self.write_next_entity_id(id + 1);
+            EntityBuilder::new(id, self)
 
         } // fn ..
 
 
-        pub const fn new_query(&self) -> QueryBuilder<B, F, P> { QueryBuilder { bit_mask: B::MIN, world: self }}
+        pub fn new_query(&self) -> QueryBuilder<B, F, P> { QueryBuilder::new(self) }
 
     } // impl ..
 
 
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Drop for World<B, F, P> {
+        fn drop(&mut self) {
+            if self.indexes.contains_key(&ComponentType::of::<Name>()) { names::forget_debug_names(self.id); }
+        } // fn ..
+    } // impl ..
+
+
     impl<B: BitField, F: BitField, P: Hash + Eq + Debug> WorldBuilder<B, F, P> {
         pub fn with_component_pointer<C: Component, T: Into<P>>(mut self, id: T, component: C) -> Self {
 
             let id = id.into();
             match self.component_pointers.contains_key(&id) {
-                true =>  { println!("The component pointer {:?} has been discarded as it was already registered!", id) },
+                true =>  { self.diagnostics.push(BuildDiagnostic::DuplicateComponentPtr(id)); },
                 false => { self.component_pointers.insert(id, Box::new(Rc::new(RefCell::new(component)))); },
             } // match ..
 
@@ -603,7 +904,7 @@
 
             let id = id.into();
             match self.component_pointers.contains_key(&id) {
-                true =>  { println!("The component pointer {:?} has been discarded as it was already registered!", id) },
+                true =>  { self.diagnostics.push(BuildDiagnostic::DuplicateComponentPtr(id)); },
                 false => { self.component_pointers.insert(id, Box::new(component.clone())); },
             } // match ..
 
@@ -614,10 +915,10 @@
 
         pub fn with_component<C: Component>(mut self) -> Self {
 
-            match self.components.contains(&TypeId::of::<C>()) {
-                true =>  { println!("The component no.{} has been discarded as it was already registered!", self.component_count ) },
+            match self.has_component::<C>() {
+                true =>  { self.diagnostics.push(BuildDiagnostic::DuplicateComponent(ComponentType::of::<C>())); },
                 false => {
-                    self.components.push(TypeId::of::<C>());
+                    self.components.push(ComponentInfo::new::<C>());
                     self.component_columns.insert(
                         B::nth_bit(self.component_count as u8),
                         Box::new(HashMap::<Entity, Rc<RefCell<C>>>::new()
@@ -631,9 +932,221 @@
         } // fn ..
 
 
+        pub fn with_scene_component<C: SceneComponent, T: Into<String>>(self, name: T) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            builder.scene_components.insert(name.into(), SceneComponentLoader::new::<C>());
+            builder
+
+        } // fn ..
+
+
+        pub fn with_journal_component<C: JournalComponent, T: Into<String>>(self, name: T) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            builder.journal_components.insert(name.into(), JournalComponentLoader::new::<C>());
+            builder
+
+        } // fn ..
+
+
+        pub fn with_debug_component<C: Component + Debug>(self) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            if let Some(info) = builder.component_info_mut::<C>() { info.with_debug::<C>(); }
+            builder
+
+        } // fn ..
+
+
+        pub fn with_reflect_component<C: Reflect>(self) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            if let Some(info) = builder.component_info_mut::<C>() { info.with_reflect::<C>(); }
+            builder
+
+        } // fn ..
+
+
+        pub fn with_hash_component<C: HashComponent>(self) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            if let Some(info) = builder.component_info_mut::<C>() { info.with_hash::<C>(); }
+            builder
+
+        } // fn ..
+
+
+        pub fn with_index<C: Component, K: Hash + Eq + Clone + 'static>(self, key: fn(&C) -> K) -> Self {
+            self.with_registered_index::<C>(KeyIndex::new(key, false))
+        } // fn ..
+
+
+        pub fn with_unique_index<C: Component, K: Hash + Eq + Clone + 'static>(self, key: fn(&C) -> K) -> Self {
+            self.with_registered_index::<C>(KeyIndex::new(key, true))
+        } // fn ..
+
+
+        pub fn with_spatial_index<C: Position>(mut self, cell_size: f32) -> Self {
+            match cell_size > 0.0 && cell_size.is_finite() {
+                true  => self.with_registered_index::<C>(SpatialIndex::<C>::new(cell_size)),
+                false => {
+
+                    self.diagnostics.push(BuildDiagnostic::InvalidCellSize(ComponentType::of::<C>()));
+                    self.with_registered_component::<C>()
+
+                }, // => ..
+            } // match ..
+        } // fn ..
+
+
+        pub fn with_names(self, unique: bool) -> Self {
+            self.with_debug_component::<Name>()
+                .with_hash_component::<Name>()
+                .with_diff_component::<Name>()
+                .with_scene_component::<Name, _>("name")
+                .with_journal_component::<Name, _>("name")
+                .with_registered_index::<Name>(KeyIndex::new(|name: &Name| name.0.clone(), unique))
+        } // fn ..
+
+
+        fn with_registered_index<C: Component>(self, index: impl ComponentIndex + 'static) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            builder.indexes
+                .entry(ComponentType::of::<C>())
+                .or_default()
+                .push(Box::new(index));
+
+            builder
+
+        } // fn ..
+
+
+        pub fn with_diff_component<C: Component + PartialEq>(self) -> Self {
+
+            let mut builder = self.with_registered_component::<C>();
+            if let Some(info) = builder.component_info_mut::<C>() { info.with_eq::<C>(); }
+            builder
+
+        } // fn ..
+
+
+        fn with_registered_component<C: Component>(self) -> Self {
+            match self.has_component::<C>() {
+                true  => self,
+                false => self.with_component::<C>(),
+            } // match ..
+        } // fn ..
+
+
+        fn has_component<C: Component>(&self) -> bool {
+            self.components
+                .iter()
+                .any(|info| info.kind == ComponentType::of::<C>())
+        } // fn ..
+
+
+        fn component_info_mut<C: Component>(&mut self) -> Option<&mut ComponentInfo> {
+            self.components
+                .iter_mut()
+                .find(|info| info.kind == ComponentType::of::<C>())
+        } // fn ..
+
+
         pub fn with_flag<T: Into<F>>(mut self, flag: T, range: Range<u8>) -> Self {
 
-            self.flags.insert(flag.into(), range);
+            let flag = flag.into();
+            if let Some(other) = self.flags
+                .iter()
+                .find(|(other, other_range)| **other != flag && other_range.start < range.end && range.start < other_range.end)
+                .map(|(other, _)| *other) { self.diagnostics.push(BuildDiagnostic::OverlappingFlags(flag, other)); }
+
+            match self.has_flag(flag) {
+                true  => { self.diagnostics.push(BuildDiagnostic::DuplicateFlag(flag)); },
+                false => { self.flags.insert(flag, range); },
+            } // match ..
+
+            self
+
+        } // fn ..
+
+
+        pub fn with_flag_bits<T: Into<F>>(mut self, flag: T, width: u8) -> Self {
+
+            let flag = flag.into();
+            match self.has_flag(flag) {
+                true  => { self.diagnostics.push(BuildDiagnostic::DuplicateFlag(flag)); },
+                false => { self.flag_widths.push((flag, width)); },
+            } // match ..
+
+            self
+
+        } // fn ..
+
+
+        fn has_flag(&self, flag: F) -> bool {
+            self.flags.contains_key(&flag) || self.flag_widths
+                .iter()
+                .any(|(other, _)| *other == flag)
+        } // fn ..
+
+
+        pub fn strict(mut self, strict: bool) -> Self {
+
+            self.strict = strict;
+            self
+
+        } // fn ..
+
+
+        pub fn with_logger(mut self, logger: impl Fn(&BuildDiagnostic<F, P>) + 'static) -> Self {
+
+            self.logger = Some(Box::new(logger));
+            self
+
+        } // fn ..
+
+
+        pub fn diagnostics(&self) -> &[BuildDiagnostic<F, P>] { &self.diagnostics }
+
+
+        pub fn with_debug_validation(mut self, debug_validation: bool) -> Self {
+
+            self.debug_validation = debug_validation;
+            self
+
+        } // fn ..
+
+
+        pub fn with_history(mut self, memory_budget: usize) -> Self {
+
+            self.history_budget = Some(memory_budget);
+            self
+
+        } // fn ..
+
+
+        pub fn with_journal(mut self, journal: bool) -> Self
+        where P: Clone {
+
+            self.journal = journal.then(|| Journal::new(P::clone));
+            self
+
+        } // fn ..
+
+
+        pub fn with_deterministic_iteration(mut self, deterministic_iteration: bool) -> Self {
+
+            self.deterministic_iteration = deterministic_iteration;
+            self
+
+        } // fn ..
+
+
+        pub fn with_snapshots(mut self, capacity: usize) -> Self {
+
+            self.snapshot_capacity = Some(capacity);
             self
 
         } // fn ..
@@ -641,24 +1154,75 @@
 
         pub fn build(self) -> Result<World<B, F, P>, EcsErr<B, F, P>> {
 
+            if self.strict && !self.diagnostics.is_empty() { return Err(EcsErr::InvalidBuilder(self.diagnostics)) }
+            if let Some(logger) = self.logger.as_ref() { self.diagnostics.iter().for_each(logger); }
+
+            let mut flags = self.flags;
+            for (flag, width) in self.flag_widths {
+
+                let mut start = 0u8;
+                while let Some(end) = flags
+                    .values()
+                    .filter(|range| range.start < start.saturating_add(width) && start < range.end)
+                    .map(|range| range.end)
+                    .max() { start = end; }
+
+                flags.insert(flag, start..start.saturating_add(width));
+
+            } // for ..
+
+
+            if let Some(flag) = flags
+                .iter()
+                .find(|(_, range)| range.is_empty())
+                .map(|(flag, _)| *flag) { return Err(EcsErr::EmptyFlagRange(flag)) }
+
+            if let Some((flag, other)) = flags
+                .iter()
+                .find_map(|(flag, range)| flags
+                    .iter()
+                    .find(|(other, other_range)| *other != flag && other_range.start < range.end && range.start < other_range.end)
+                    .map(|(other, _)| (*flag, *other))
+                ) { return Err(EcsErr::OverlappingFlagRanges(flag, other)) }
+
+
             let range = usize::from(B::BITS);
-            let size  = self.component_count + usize::from(
-                match self.flags.iter().max_by(|(_, a), (_, b)| a.start.cmp(&b.end)) {
-                    Some(max) => max.1.end,
-                    None      => 0u8,
-                } // match ..
+            let size  = self.component_count + 1usize + usize::from(flags
+                .values()
+                .map(|range| range.end)
+                .max()
+                .unwrap_or_default()
             ); // let ..
 
 
             match size > range {
                 true  => Err(EcsErr::BitFieldRangeTooSmall(size, range)),
                 false => Ok(World {
-                    components:         self.components,
-                    flags:              self.flags,
-                    component_columns:  self.component_columns,
-                    component_pointers: self.component_pointers,
-                    entities:           HashMap::default(),
-                    next_entity_id:     0usize,
+                    id:                      NEXT_WORLD_ID.fetch_add(1usize, Ordering::Relaxed),
+                    components:              self.components,
+                    flags,
+                    component_columns:       self.component_columns,
+                    component_pointers:      self.component_pointers,
+                    scene_components:        self.scene_components,
+                    entities:                HashMap::default(),
+                    parents:                 HashMap::default(),
+                    next_entity_id:          0usize,
+                    debug_validation:        self.debug_validation,
+                    changes:                 self.history_budget.map(|_| Vec::new()),
+                    history:                 self.history_budget.map(History::new),
+                    journal_components:      self.journal_components,
+                    journal:                 self.journal,
+                    deterministic_iteration: self.deterministic_iteration,
+                    snapshots:               self.snapshot_capacity.map(SnapshotBuffer::new),
+                    column_borrows:          Rc::default(),
+                    next_query_id:           Cell::default(),
+                    signature_log:           VecDeque::default(),
+                    signature_log_start:     0usize,
+                    indexes:                 self.indexes,
+                    index_conflict:          None,
+                    atomic_depth:            0usize,
+                    lent_cells:              RefCell::default(),
+                    cell_holders:            HashMap::default(),
                 }) // => ..
             } // match ..
         } // fn ..
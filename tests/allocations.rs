@@ -0,0 +1,175 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use hopper::{World, Component};
+
+    mod common;
+    use common::Health;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    struct CountingAllocator;
+
+
+    #[derive(Debug, PartialEq)]
+    struct Label(String);
+
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0usize) };
+    } // thread_local!
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+
+            let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1usize));
+            unsafe { System.alloc(layout) }
+
+        } // fn ..
+
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) { unsafe { System.dealloc(ptr, layout) } }
+
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+
+            let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1usize));
+            unsafe { System.realloc(ptr, layout, new_size) }
+
+        } // fn ..
+    } // impl ..
+
+
+    impl Clone for Label {
+        fn clone(&self)                  -> Self { Label(self.0.clone()) }
+        fn clone_from(&mut self, source: &Self) { self.0.clone_from(&source.0) }
+    } // impl ..
+
+
+    impl Component for Label {}
+
+
+    fn allocations(f: impl FnOnce()) -> usize {
+
+        let before = ALLOCATIONS.with(|allocations| allocations.get());
+        f();
+        ALLOCATIONS.with(|allocations| allocations.get()) - before
+
+    } // fn ..
+
+
+    #[test]
+    fn saving_snapshots_reuses_evicted_snapshots() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_component::<Label>()
+            .with_component_pointer(0u8, Health(50))
+            .with_snapshots(4)
+            .build()
+            .unwrap();
+
+        let entities = (0..256)
+            .map(|index| world
+                .new_entity()
+                .with_component(Health(index)).unwrap()
+                .with_component(Label(format!("entity {}", index))).unwrap()
+                .build()
+            ).collect::<Vec<hopper::Entity>>();
+
+        world.set_entity_parent(entities[1], entities[0]).unwrap();
+        for tick in 0..4u64 { world.save_snapshot(tick); }
+
+        for tick in 4..64u64 {
+
+            world.modify_entity_component::<Health>(entities[tick as usize], |health| health.0 += 1).unwrap();
+            assert_eq!(allocations(|| world.save_snapshot(tick)), 0usize);
+
+        } // for ..
+    } // fn ..
+
+
+    #[test]
+    fn iterating_queries_does_not_allocate() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_component::<Label>()
+            .build()
+            .unwrap();
+
+        let entities = (0..256)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<hopper::Entity>>();
+
+        world.add_component_to_entity(Label(String::from("player")), entities[0]).unwrap();
+
+        let mut total = 0i32;
+        assert_eq!(allocations(|| {
+
+            let query = world.new_query().with_component::<Health>().unwrap().build();
+            for (_, health) in query.iter_components::<Health>().unwrap() { total += health.borrow().0; }
+
+            assert_eq!(query.iter_entities().count(), 256usize);
+            assert_eq!(query.count(), 256usize);
+            assert!(query.first().is_some());
+            assert!(query.contains(entities[255]));
+            assert!(!query.is_empty());
+
+            let players = world.new_query().with_component::<Label>().unwrap().build();
+            assert_eq!(players.single(), Some(entities[0]));
+
+        }), 0usize);
+
+        assert_eq!(total, (0..256).sum::<i32>());
+
+    } // fn ..
+
+
+    #[test]
+    fn borrowing_query_components_does_not_allocate() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .build()
+            .unwrap();
+
+        let entities = (0..256)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<hopper::Entity>>();
+
+        let query     = world.new_query().write::<Health>().unwrap().build();
+        let mut total = 0i32;
+        assert_eq!(allocations(|| {
+
+            for (_, mut health) in query.components_mut::<Health>().unwrap().iter_mut() { health.0 += 1; }
+            assert_eq!(query.components_mut::<Health>().unwrap().get_mut(entities[7]).map(|health| health.0), Some(8));
+
+            let components = query.components::<Health>().unwrap();
+            for (_, health) in components.iter() { total += health.0; }
+            assert_eq!(components.len(), 256usize);
+            drop(components);
+
+            for health in query.get_components::<Health>().unwrap() { total -= health.borrow().0; }
+
+        }), 0usize);
+
+        assert_eq!(total, 0i32);
+
+    } // fn ..
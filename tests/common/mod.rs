@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::hash::{Hash, Hasher};
+
+    use hopper::{World, Component, JournalComponent, HashComponent};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Health(pub i32);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Health {}
+
+
+    impl JournalComponent for Health {
+        fn to_journal(&self)         -> String       { self.0.to_string() }
+        fn from_journal(value: &str) -> Option<Self> { value.parse().ok().map(Health) }
+    } // impl ..
+
+
+    impl HashComponent for Health {
+        fn hash_component<H: Hasher>(&self, state: &mut H) { self.0.hash(state) }
+    } // impl ..
+
+
+    pub fn world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    pub fn health(world: &World<u16, u8, u8>, entity: hopper::Entity) -> i32 { world.get_entity_component::<Health>(entity).unwrap().unwrap().borrow().0 }
@@ -0,0 +1,65 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Component, ComponentDescription, Reflect, EcsErrKind};
+
+    mod common;
+    use common::Health;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Position { x: i32, y: i32 }
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Position {}
+
+
+    impl Reflect for Position {
+        fn fields(&self) -> Vec<(&'static str, String)> { vec![("x", self.x.to_string()), ("y", self.y.to_string())] }
+    } // impl ..
+
+
+    #[test]
+    fn entity_components_describe_debug_and_reflected_values() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_debug_component::<Position>()
+            .with_reflect_component::<Position>()
+            .build()
+            .unwrap();
+
+        let entity = world
+            .new_entity()
+            .with_component(Health(3)).unwrap()
+            .with_component(Position { x: 1, y: -2 }).unwrap()
+            .build();
+
+        assert_eq!(world.entity_components(entity).unwrap(), vec![
+            ComponentDescription {
+                name:   std::any::type_name::<Health>(),
+                size:   std::mem::size_of::<Health>(),
+                value:  None,
+                fields: Vec::new(),
+            }, // ComponentDescription
+            ComponentDescription {
+                name:   std::any::type_name::<Position>(),
+                size:   std::mem::size_of::<Position>(),
+                value:  Some(String::from("Position { x: 1, y: -2 }")),
+                fields: vec![("x", String::from("1")), ("y", String::from("-2"))],
+            }, // ComponentDescription
+        ]); // assert_eq!()
+
+        world.delete_entity(entity).unwrap();
+        assert_eq!(world.entity_components(entity).err().unwrap().kind(), EcsErrKind::MissingEntity);
+
+    } // fn ..
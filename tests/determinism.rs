@@ -0,0 +1,71 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Entity};
+
+    mod common;
+    use common::Health;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn deterministic_world() -> (World<u16, u8, u8>, Vec<Entity>) {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_deterministic_iteration(true)
+            .build()
+            .unwrap();
+
+        let entities = (0..64)
+            .map(|index| world.new_entity().with_component(Health(index % 4)).unwrap().build())
+            .collect::<Vec<Entity>>();
+
+        for entity in entities.iter().skip(1).step_by(3) { world.delete_entity(*entity).unwrap(); }
+        for child in entities.iter().skip(2).step_by(3) { world.set_entity_parent(*child, entities[0]).unwrap(); }
+
+        (world, entities)
+
+    } // fn ..
+
+
+    #[test]
+    fn deterministic_worlds_iterate_by_entity_id() {
+
+        let (world, entities) = deterministic_world();
+        let alive             = entities
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| index % 3 != 1)
+            .map(|(_, entity)| *entity)
+            .collect::<Vec<Entity>>();
+
+        let query = world.new_query().with_component::<Health>().unwrap().build();
+        assert_eq!(query.get_entities(), alive);
+        assert_eq!(query.iter_components::<Health>().unwrap().map(|(entity, _)| entity).collect::<Vec<Entity>>(), alive);
+        assert_eq!(world.get_entity_children(entities[0]), entities.iter().skip(2).step_by(3).copied().collect::<Vec<Entity>>());
+
+        for _ in 0..8 { assert_eq!(world.new_query().build().get_entities(), alive); }
+
+    } // fn ..
+
+
+    #[test]
+    fn sorted_queries_keep_ties_in_id_order() {
+
+        let (world, _) = deterministic_world();
+        let health     = |entity: &Entity| world.get_entity_component::<Health>(*entity).unwrap().unwrap().borrow().0;
+
+        let sorted = world.new_query().with_component::<Health>().unwrap().build().sorted_by_key(|entity| health(entity)).get_entities();
+        let mut expected = world.new_query().build().get_entities();
+        expected.sort_by_key(|entity| (health(entity), *entity));
+
+        assert_eq!(sorted, expected);
+        for _ in 0..8 {
+            assert_eq!(world.new_query().with_component::<Health>().unwrap().build().sorted_by_key(|entity| health(entity)).get_entities(), sorted);
+        } // for ..
+
+    } // fn ..
@@ -0,0 +1,70 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    use hopper::{World, BuildDiagnostic, ComponentType, EcsErr};
+
+    mod common;
+    use common::Health;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    #[test]
+    fn logger_receives_every_diagnostic_once() {
+
+        let logged  = Rc::new(RefCell::new(Vec::<BuildDiagnostic<u8, u8>>::new()));
+        let builder = World::<u16, u8, u8>::builder()
+            .with_logger({ let logged = logged.clone(); move |diagnostic| logged.borrow_mut().push(diagnostic.clone()) })
+            .with_component::<Health>()
+            .with_component::<Health>()
+            .with_component_pointer(0u8, Health(1))
+            .with_component_pointer(0u8, Health(2))
+            .with_flag(0u8, 0..2)
+            .with_flag(0u8, 4..5);
+
+        let expected = vec![
+            BuildDiagnostic::DuplicateComponent(ComponentType::of::<Health>()),
+            BuildDiagnostic::DuplicateComponentPtr(0u8),
+            BuildDiagnostic::DuplicateFlag(0u8),
+        ]; // let ..
+
+        assert_eq!(builder.diagnostics(), expected.as_slice());
+        assert!(logged.borrow().is_empty());
+
+        let world = builder.build().unwrap();
+        assert_eq!(*logged.borrow(), expected);
+        assert_eq!(world.get_pointer_component::<Health>(0u8).unwrap().borrow().0, 1);
+
+    } // fn ..
+
+
+    #[test]
+    fn strict_builders_fail_with_their_diagnostics() {
+
+        let err = World::<u16, u8, u8>::builder()
+            .strict(true)
+            .with_component::<Health>()
+            .with_component::<Health>()
+            .build()
+            .err()
+            .unwrap();
+
+        match &err {
+            EcsErr::InvalidBuilder(diagnostics) => assert_eq!(diagnostics, &vec![BuildDiagnostic::DuplicateComponent(ComponentType::of::<Health>())]),
+            _                                   => panic!("unexpected error `{}`", err),
+        } // match ..
+
+        assert_eq!(err.to_string(), format!(
+            "The world builder is invalid: The component `{}` has been discarded as it was already registered!",
+            std::any::type_name::<Health>(),
+        )); // assert_eq!()
+
+        assert!(World::<u16, u8, u8>::builder().strict(true).with_component::<Health>().build().is_ok());
+
+    } // fn ..
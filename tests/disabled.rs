@@ -0,0 +1,99 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Entity};
+
+    mod common;
+    use common::{Health, world};
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn named_world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_names(true)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    fn sorted(mut entities: Vec<Entity>) -> Vec<Entity> {
+
+        entities.sort();
+        entities
+
+    } // fn ..
+
+
+    #[test]
+    fn queries_skip_disabled_entities() {
+
+        let mut world = world();
+        let entities  = (0..4)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<Entity>>();
+
+        world.disable_entity(entities[1]).unwrap();
+        world.disable_entity(entities[3]).unwrap();
+
+        let query = world.new_query().with_component::<Health>().unwrap().read::<Health>().unwrap().build();
+        assert_eq!(sorted(query.get_entities()), vec![entities[0], entities[2]]);
+        assert_eq!(query.count(), 2usize);
+        assert!(!query.contains(entities[1]));
+        assert!(query.components::<Health>().unwrap().get(entities[1]).is_none());
+        drop(query);
+
+        let mut state = world.new_query().with_component::<Health>().unwrap().into_state();
+        assert_eq!(sorted(state.query(&world).unwrap().get_entities()), vec![entities[0], entities[2]]);
+
+        world.enable_entity(entities[1]).unwrap();
+        let query = world.new_query().with_component::<Health>().unwrap().build();
+        assert_eq!(sorted(query.get_entities()), vec![entities[0], entities[1], entities[2]]);
+
+    } // fn ..
+
+
+    #[test]
+    fn include_disabled_brings_disabled_entities_back() {
+
+        let mut world = world();
+        let entities  = (0..4)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<Entity>>();
+
+        world.disable_entity(entities[1]).unwrap();
+
+        let query = world.new_query().with_component::<Health>().unwrap().read::<Health>().unwrap().include_disabled().build();
+        assert_eq!(sorted(query.get_entities()), entities);
+        assert_eq!(query.components::<Health>().unwrap().get(entities[1]).map(|health| health.0), Some(1));
+        drop(query);
+
+        let mut state = world.new_query().with_component::<Health>().unwrap().include_disabled().into_state();
+        assert_eq!(sorted(state.query(&world).unwrap().get_entities()), entities);
+
+    } // fn ..
+
+
+    #[test]
+    fn lookups_and_group_getters_skip_disabled_entities() {
+
+        let mut world = named_world();
+        let player    = world.new_entity().with_name("player").unwrap().with_component(Health(1)).unwrap().build();
+        let other     = world.new_entity().with_component(Health(2)).unwrap().build();
+
+        world.disable_entity(player).unwrap();
+        assert_eq!(world.entity_by_name("player").unwrap(), None);
+        assert_eq!(world.lookup::<hopper::Name, String>(&String::from("player")).unwrap().count(), 0usize);
+        assert!(world.get_some_entity_group_component::<Health>(&[player, other]).unwrap()[0].is_none());
+        assert_eq!(world.get_entity_group_component::<Health>(&[player, other]).unwrap().len(), 1usize);
+
+        world.enable_entity(player).unwrap();
+        assert_eq!(world.entity_by_name("player").unwrap(), Some(player));
+        assert!(world.get_some_entity_group_component::<Health>(&[player, other]).unwrap()[0].is_some());
+        assert_eq!(world.get_entity_group_component::<Health>(&[player, other]).unwrap().len(), 2usize);
+
+    } // fn ..
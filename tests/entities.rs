@@ -0,0 +1,52 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::error::Error;
+
+    use hopper::EcsErrKind;
+
+    mod common;
+    use common::{Health, world, health};
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    #[test]
+    fn group_deletion_with_a_missing_entity_deletes_nothing() {
+
+        let mut world = world();
+        let first     = world.new_entity().with_component(Health(1)).unwrap().build();
+        let deleted   = world.new_entity().with_component(Health(2)).unwrap().build();
+        let last      = world.new_entity().with_component(Health(3)).unwrap().build();
+        world.delete_entity(deleted).unwrap();
+
+        let err = world.delete_entity_group(&[first, deleted, last]).unwrap_err();
+        assert_eq!(err.operation(), Some("delete_entity_group"));
+        assert_eq!(err.kind(), EcsErrKind::MissingEntity);
+        assert_eq!(err.source().unwrap().to_string(), format!("The entities `{:?}` are not registered!", vec![deleted]));
+
+        assert_eq!(health(&world, first), 1);
+        assert_eq!(health(&world, last), 3);
+        assert!(world.validate().is_valid());
+
+    } // fn ..
+
+
+    #[test]
+    fn group_deletion_removes_every_entity_once() {
+
+        let mut world = world();
+        let parent    = world.new_entity().with_component(Health(1)).unwrap().build();
+        let child     = world.new_entity().with_component(Health(2)).unwrap().build();
+        let kept      = world.new_entity().with_component(Health(3)).unwrap().build();
+        world.set_entity_parent(child, parent).unwrap();
+
+        world.delete_entity_group(&[parent, child, parent]).unwrap();
+
+        assert_eq!(world.new_query().build().get_entities(), vec![kept]);
+        assert!(world.validate().is_valid());
+
+    } // fn ..
@@ -0,0 +1,47 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::error::Error;
+
+    use hopper::{Prefab, EcsErrKind};
+
+    mod common;
+    use common::{Health, world};
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    #[test]
+    fn nested_operations_are_wrapped_once() {
+
+        let mut world = world();
+
+        let broken = Prefab::new()
+            .with_child(Prefab::new().with_component(Health(1)))
+            .with_flag(1u8, None);
+
+        let err = world.spawn_prefab(&broken).unwrap_err();
+
+        assert_eq!(err.operation(), Some("spawn_prefab"));
+        assert_eq!(err.kind(), EcsErrKind::MissingFlag);
+        assert_eq!(err.to_string(), "The operation `spawn_prefab` failed!");
+        assert!(err.source().is_some_and(|source| source.source().is_none()));
+
+    } // fn ..
+
+
+    #[test]
+    fn public_calls_made_by_an_operation_are_reported_as_that_operation() {
+
+        let mut world = world();
+
+        let err = world.load_scene("entity a\n    flag 3\n").err().unwrap();
+
+        assert_eq!(err.operation(), Some("load_scene"));
+        assert_eq!(err.kind(), EcsErrKind::MissingFlag);
+        assert!(err.source().is_some_and(|source| source.source().is_none()));
+
+    } // fn ..
@@ -0,0 +1,123 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Entity, Component, EcsErrKind};
+
+    mod common;
+    use common::Health;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Player;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Player {}
+
+
+    fn world() -> (World<u16, u8, u8>, Vec<Entity>) {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_component::<Player>()
+            .build()
+            .unwrap();
+
+        let entities = (0..8)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<Entity>>();
+
+        world.new_entity().with_component(Player).unwrap().build();
+        world.add_component_to_entity(Player, entities[6]).unwrap();
+
+        (world, entities)
+
+    } // fn ..
+
+
+    #[test]
+    fn predicates_keep_matching_components() {
+
+        let (world, entities) = world();
+        let query             = world.new_query().filter::<Health>(|health| health.0 % 2 == 0).unwrap().build();
+
+        let mut matched = query.get_entities();
+        matched.sort();
+        assert_eq!(matched, entities.iter().step_by(2).copied().collect::<Vec<Entity>>());
+        assert_eq!(query.count(), 4usize);
+        assert!(!query.is_empty());
+        assert!(query.contains(entities[4]));
+        assert!(!query.contains(entities[3]));
+        assert!(query.first().is_some_and(|entity| matched.contains(&entity)));
+        assert!(query.iter_components::<Health>().unwrap().all(|(_, health)| health.borrow().0 % 2 == 0));
+
+    } // fn ..
+
+
+    #[test]
+    fn predicates_are_combined_and_see_current_values() {
+
+        let (world, entities) = world();
+        let query             = world.new_query()
+            .named("player")
+            .filter::<Health>(|health| health.0 > 2)
+            .unwrap()
+            .filter::<Health>(|health| health.0 < 7)
+            .unwrap()
+            .with_component::<Player>()
+            .unwrap()
+            .build();
+
+        assert_eq!(query.single(), Some(entities[6]));
+        assert_eq!(query.get_single().unwrap(), entities[6]);
+
+        world.get_entity_component::<Health>(entities[6]).unwrap().unwrap().borrow_mut().0 = 7;
+        assert!(query.is_empty());
+        assert_eq!(query.single(), None);
+
+        let err = query.get_single().err().unwrap();
+        assert_eq!(err.kind(), EcsErrKind::NotSingleQuery);
+        assert_eq!(err.to_string(), "The query `player` matches 0 entities instead of a single one!");
+
+    } // fn ..
+
+
+    #[test]
+    fn predicates_skip_entities_without_the_component() {
+
+        let (world, entities) = world();
+        let query             = world.new_query().filter::<Health>(|_| true).unwrap().build();
+
+        assert_eq!(query.count(), entities.len());
+        assert_eq!(world.new_query().with_component::<Player>().unwrap().build().count(), 2usize);
+
+        let listed = world.new_query().filter::<Health>(|health| health.0 < 4).unwrap().with_entities([entities[1], entities[5]]).build();
+        assert_eq!(listed.get_entities(), vec![entities[1]]);
+
+        assert_eq!(listed.get_single().unwrap(), entities[1]);
+
+    } // fn ..
+
+
+    #[test]
+    fn cached_queries_apply_their_predicates() {
+
+        let (mut world, entities) = world();
+        let mut state             = world.new_query().filter::<Health>(|health| health.0 >= 6).unwrap().into_state();
+
+        assert_eq!(state.query(&world).unwrap().count(), 2usize);
+
+        world.modify_entity_component::<Health>(entities[0], |health| health.0 = 10).unwrap();
+        let query = state.query(&world).unwrap();
+        assert_eq!(query.count(), 3usize);
+        assert!(query.contains(entities[0]));
+
+    } // fn ..
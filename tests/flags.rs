@@ -0,0 +1,98 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Component, EcsErr, EcsErrKind};
+
+    mod common;
+    use common::Health;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Armor(i32);
+
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Speed(i32);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Armor {}
+    impl Component for Speed {}
+
+
+    #[test]
+    fn overlapping_and_empty_ranges_are_rejected() {
+
+        let overlapping = World::<u16, u8, u8>::builder()
+            .with_flag(0u8, 0..2)
+            .with_flag(1u8, 1..3)
+            .build()
+            .err()
+            .unwrap();
+
+        assert!(matches!(overlapping, EcsErr::OverlappingFlagRanges(0u8, 1u8) | EcsErr::OverlappingFlagRanges(1u8, 0u8)));
+        assert_eq!(overlapping.kind(), EcsErrKind::InvalidFlagRange);
+
+        let empty = World::<u16, u8, u8>::builder().with_flag(0u8, 2..2).build().err().unwrap();
+        assert!(matches!(empty, EcsErr::EmptyFlagRange(0u8)));
+
+        let zero_width = World::<u16, u8, u8>::builder().with_flag_bits(0u8, 0).build().err().unwrap();
+        assert!(matches!(zero_width, EcsErr::EmptyFlagRange(0u8)));
+
+        let too_wide = World::<u8, u8, u8>::builder().with_component::<Health>().with_flag_bits(0u8, 7).build().err().unwrap();
+        assert!(matches!(too_wide, EcsErr::BitFieldRangeTooSmall(9usize, 8usize)));
+
+    } // fn ..
+
+
+    #[test]
+    fn auto_allocated_flags_fill_the_free_bits() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_flag(0u8, 0..2)
+            .with_flag_bits(1u8, 3)
+            .with_flag(2u8, 5..6)
+            .build()
+            .unwrap();
+
+        let entity = world.new_entity().build();
+        world.set_entity_flag(entity, 1u8, Some(0b111)).unwrap();
+
+        assert!(world.entity_has_flag(entity, 1u8, Some(0b111)).unwrap());
+        assert!(!world.entity_has_flag(entity, 0u8, Some(0b01)).unwrap());
+        assert!(!world.entity_has_flag(entity, 0u8, Some(0b10)).unwrap());
+        assert!(!world.entity_has_flag(entity, 2u8, None).unwrap());
+
+    } // fn ..
+
+
+    #[test]
+    fn flag_bits_are_shifted_past_the_components() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_component::<Armor>()
+            .with_component::<Speed>()
+            .with_flag(0u8, 0..3)
+            .build()
+            .unwrap();
+
+        let entity = world.new_entity().build();
+        world.set_entity_flag(entity, 0u8, None).unwrap();
+
+        assert!(world.entity_has_flag(entity, 0u8, None).unwrap());
+        assert!(world.entity_components(entity).unwrap().is_empty());
+        assert!(world.new_query().with_component::<Health>().unwrap().build().is_empty());
+        assert!(world.new_query().with_component::<Speed>().unwrap().build().is_empty());
+        assert_eq!(world.new_query().with_flag(0u8, None).unwrap().build().get_entities(), vec![entity]);
+
+    } // fn ..
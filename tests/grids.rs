@@ -0,0 +1,109 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Component, Position, BuildDiagnostic, ComponentType, EcsErrKind};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Transform(f32, f32);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Transform {}
+
+
+    impl Position for Transform {
+        fn position(&self) -> [f32; 3] { [self.0, self.1, 0.0] }
+    } // impl ..
+
+
+    #[test]
+    fn invalid_cell_sizes_discard_the_spatial_index() {
+        for cell_size in [0.0, -8.0, f32::NAN, f32::INFINITY] {
+
+            let builder = World::<u16, u8, u8>::builder().with_spatial_index::<Transform>(cell_size);
+            assert_eq!(builder.diagnostics(), &[BuildDiagnostic::InvalidCellSize(ComponentType::of::<Transform>())]);
+
+            let mut world = builder.build().unwrap();
+            world.new_entity().with_component(Transform(1.0, 1.0)).unwrap().build();
+            assert_eq!(world.within_radius::<Transform>([0.0, 0.0, 0.0], 4.0).err().unwrap().kind(), EcsErrKind::MissingIndex);
+
+            let strict = World::<u16, u8, u8>::builder().strict(true).with_spatial_index::<Transform>(cell_size).build();
+            assert_eq!(strict.err().unwrap().kind(), EcsErrKind::InvalidBuilder);
+
+        } // for ..
+    } // fn ..
+
+
+    #[test]
+    fn spatial_positions_can_be_moved_through_a_query() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_spatial_index::<Transform>(8.0)
+            .build()
+            .unwrap();
+
+        let entity = world.new_entity().with_component(Transform(1.0, 1.0)).unwrap().build();
+        let query  = world.new_query().write::<Transform>().unwrap().build();
+
+        query.par_for_each::<Transform>(1, |_, transform| transform.0 += 100.0).unwrap();
+        assert!(world.within_radius::<Transform>([1.0, 1.0, 0.0], 4.0).unwrap().is_empty());
+        assert_eq!(world.within_radius::<Transform>([101.0, 1.0, 0.0], 4.0).unwrap(), vec![entity]);
+        assert_eq!(world.within_aabb::<Transform>([96.0, 0.0, 0.0], [104.0, 2.0, 0.0]).unwrap(), vec![entity]);
+        assert_eq!(world.nearest::<Transform>([90.0, 0.0, 0.0]).unwrap(), Some(entity));
+        drop(query);
+
+        world.modify_entity_component::<Transform>(entity, |transform| transform.0 += 100.0).unwrap();
+        assert!(world.within_radius::<Transform>([101.0, 1.0, 0.0], 4.0).unwrap().is_empty());
+        assert_eq!(world.within_radius::<Transform>([201.0, 1.0, 0.0], 4.0).unwrap(), vec![entity]);
+
+    } // fn ..
+
+
+    #[test]
+    fn spatial_lookups_skip_disabled_entities() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_spatial_index::<Transform>(8.0)
+            .build()
+            .unwrap();
+
+        let near = world.new_entity().with_component(Transform(1.0, 1.0)).unwrap().build();
+        let far  = world.new_entity().with_component(Transform(40.0, 1.0)).unwrap().build();
+
+        world.disable_entity(near).unwrap();
+        assert_eq!(world.within_radius::<Transform>([1.0, 1.0, 0.0], 4.0).unwrap(), vec![]);
+        assert_eq!(world.within_aabb::<Transform>([0.0, 0.0, 0.0], [2.0, 2.0, 0.0]).unwrap(), vec![]);
+        assert_eq!(world.nearest::<Transform>([0.0, 0.0, 0.0]).unwrap(), Some(far));
+
+        world.enable_entity(near).unwrap();
+        assert_eq!(world.within_radius::<Transform>([1.0, 1.0, 0.0], 4.0).unwrap(), vec![near]);
+        assert_eq!(world.within_aabb::<Transform>([0.0, 0.0, 0.0], [2.0, 2.0, 0.0]).unwrap(), vec![near]);
+        assert_eq!(world.nearest::<Transform>([0.0, 0.0, 0.0]).unwrap(), Some(near));
+
+    } // fn ..
+
+
+    #[test]
+    fn nearest_handles_extreme_coordinates() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_spatial_index::<Transform>(1.0)
+            .build()
+            .unwrap();
+
+        let low  = world.new_entity().with_component(Transform(-1e12, -1e12)).unwrap().build();
+        let high = world.new_entity().with_component(Transform(1e12, 1e12)).unwrap().build();
+
+        assert_eq!(world.nearest::<Transform>([-1e12, -1e12, 0.0]).unwrap(), Some(low));
+        assert_eq!(world.nearest::<Transform>([1e12, 1e12, 0.0]).unwrap(), Some(high));
+
+    } // fn ..
@@ -0,0 +1,32 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, ComponentType};
+
+    mod common;
+    use common::Health;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    #[test]
+    fn state_hash_is_stable() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_hash_component::<Health>()
+            .build()
+            .unwrap();
+
+        let parent = world.new_entity().with_component(Health(10)).unwrap().build();
+        let child  = world.new_entity().with_component(Health(-3)).unwrap().build();
+        world.set_entity_parent(child, parent).unwrap();
+
+        let hash = world.state_hash();
+        assert_eq!(hash.entities(), 0x8fca8d8bfe2f4e29u64);
+        assert_eq!(hash.component(ComponentType::of::<Health>()), Some(0x4d25faee2da23b51u64));
+        assert_eq!(hash.total(), 0x488496e734021023u64);
+
+    } // fn ..
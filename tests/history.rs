@@ -0,0 +1,88 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, EcsErrKind};
+
+    mod common;
+    use common::Health;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn world(memory_budget: usize) -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_history(memory_budget)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    #[test]
+    fn pending_changes_count_against_the_budget() {
+
+        let mut world = world(4096);
+        let entity    = world.new_entity().with_component(Health(0)).unwrap().build();
+        world.commit_step("spawn").unwrap();
+
+        for value in 0..64 {
+
+            world.modify_entity_component::<Health>(entity, |health| health.0 = value).unwrap();
+            let history = world.history().unwrap();
+            assert!(history.memory_usage() <= history.memory_budget());
+
+        } // for ..
+    } // fn ..
+
+
+    #[test]
+    fn pending_changes_evict_the_oldest_steps_first() {
+
+        let mut world = world(4096);
+        let entity    = world.new_entity().with_component(Health(0)).unwrap().build();
+        world.commit_step("spawn").unwrap();
+
+        let mut value = 0;
+        while world.history().unwrap().undo_steps().next() == Some("spawn") {
+
+            value += 1;
+            world.modify_entity_component::<Health>(entity, |health| health.0 = value).unwrap();
+
+        } // while ..
+
+        world.commit_step("edits").unwrap();
+        assert_eq!(world.history().unwrap().undo_steps().collect::<Vec<&str>>(), vec!["edits"]);
+        assert_eq!(world.undo().unwrap().as_deref(), Some("edits"));
+        assert_eq!(world.get_entity_component::<Health>(entity).unwrap().unwrap().borrow().0, 0);
+
+    } // fn ..
+
+
+    #[test]
+    fn history_steps_are_rejected_inside_a_transaction() {
+
+        let mut world = world(4096);
+        let entity    = world.new_entity().with_component(Health(0)).unwrap().build();
+        world.commit_step("spawn").unwrap();
+
+        let result = world.transaction(|world| {
+
+            world.modify_entity_component::<Health>(entity, |health| health.0 = 1)?;
+            assert_eq!(world.commit_step("edit").unwrap_err().kind(), EcsErrKind::OpenTransaction);
+            assert_eq!(world.undo().unwrap_err().kind(), EcsErrKind::OpenTransaction);
+            assert_eq!(world.redo().unwrap_err().kind(), EcsErrKind::OpenTransaction);
+            Ok(())
+
+        }); // let ..
+
+        assert!(result.is_ok());
+        assert_eq!(world.history().unwrap().undo_steps().collect::<Vec<&str>>(), vec!["spawn"]);
+
+        world.commit_step("edit").unwrap();
+        assert_eq!(world.undo().unwrap().as_deref(), Some("edit"));
+        assert_eq!(world.get_entity_component::<Health>(entity).unwrap().unwrap().borrow().0, 0);
+
+    } // fn ..
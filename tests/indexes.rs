@@ -0,0 +1,127 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Entity, Component, EcsErrKind};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Team(u8);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Team {}
+
+
+    fn world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_index::<Team, u8>(|team| team.0)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    fn team(world: &World<u16, u8, u8>, entity: Entity) -> u8 { world.get_entity_component::<Team>(entity).unwrap().unwrap().borrow().0 }
+
+
+    #[test]
+    fn queries_can_write_indexed_components() {
+
+        let mut world = world();
+        for _ in 0..4 { world.new_entity().with_component(Team(1)).unwrap().build(); }
+
+        let query = world.new_query().write::<Team>().unwrap().build();
+        for (_, mut team) in query.components_mut::<Team>().unwrap().iter_mut() { team.0 = 2; }
+        assert_eq!(world.lookup::<Team, u8>(&1).unwrap().count(), 0usize);
+        assert_eq!(world.lookup::<Team, u8>(&2).unwrap().count(), 4usize);
+
+        query.par_for_each::<Team>(1, |_, team| team.0 = 3).unwrap();
+        assert_eq!(world.lookup::<Team, u8>(&3).unwrap().count(), 4usize);
+        drop(query);
+
+        world.new_entity().with_component(Team(3)).unwrap().build();
+        assert_eq!(world.lookup::<Team, u8>(&2).unwrap().count(), 0usize);
+        assert_eq!(world.lookup::<Team, u8>(&3).unwrap().count(), 5usize);
+
+    } // fn ..
+
+
+    #[test]
+    fn writes_made_through_handles_are_reindexed() {
+
+        let mut world = world();
+        let entity    = world.new_entity().with_component(Team(1)).unwrap().build();
+
+        world.get_entity_component::<Team>(entity).unwrap().unwrap().borrow_mut().0 = 2;
+        assert_eq!(world.lookup::<Team, u8>(&1).unwrap().count(), 0usize);
+        assert_eq!(world.lookup::<Team, u8>(&2).unwrap().collect::<Vec<Entity>>(), vec![entity]);
+
+        world.new_entity().build();
+        assert_eq!(world.lookup::<Team, u8>(&1).unwrap().count(), 0usize);
+        assert_eq!(world.lookup::<Team, u8>(&2).unwrap().collect::<Vec<Entity>>(), vec![entity]);
+
+    } // fn ..
+
+
+    #[test]
+    fn duplicate_unique_keys_are_rolled_back() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_unique_index::<Team, u8>(|team| team.0)
+            .build()
+            .unwrap();
+
+        let first  = world.new_entity().with_component(Team(1)).unwrap().build();
+        let second = world.new_entity().with_component(Team(2)).unwrap().build();
+
+        let err = world.transaction(|world| {
+
+            world.get_entity_component::<Team>(second)?.unwrap().borrow_mut().0 = 1;
+            Ok(())
+
+        }).err().unwrap(); // let ..
+
+        assert_eq!(err.kind(), EcsErrKind::DuplicateIndexKey);
+        assert_eq!(team(&world, second), 2);
+
+        let err = world.transaction(|world| {
+
+            let query = world.new_query().write::<Team>()?.build();
+            for (_, mut team) in query.components_mut::<Team>()?.iter_mut() { team.0 = 7; }
+            Ok(())
+
+        }).err().unwrap(); // let ..
+
+        assert_eq!(err.kind(), EcsErrKind::DuplicateIndexKey);
+        assert_eq!((team(&world, first), team(&world, second)), (1, 2));
+        assert_eq!(world.lookup::<Team, u8>(&1).unwrap().collect::<Vec<Entity>>(), vec![first]);
+        assert_eq!(world.lookup::<Team, u8>(&2).unwrap().collect::<Vec<Entity>>(), vec![second]);
+        assert_eq!(world.lookup::<Team, u8>(&7).unwrap().count(), 0usize);
+
+    } // fn ..
+
+
+    #[test]
+    fn writes_made_inside_a_transaction_are_reindexed() {
+
+        let mut world = world();
+        let entity    = world.new_entity().with_component(Team(1)).unwrap().build();
+
+        world.transaction(|world| {
+
+            world.get_entity_component::<Team>(entity)?.unwrap().borrow_mut().0 = 2;
+            Ok(())
+
+        }).unwrap();
+
+        assert_eq!(world.lookup::<Team, u8>(&1).unwrap().count(), 0usize);
+        assert_eq!(world.lookup::<Team, u8>(&2).unwrap().collect::<Vec<Entity>>(), vec![entity]);
+
+    } // fn ..
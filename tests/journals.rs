@@ -0,0 +1,135 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::rc::Rc;
+
+    use hopper::{World, EcsErrKind};
+
+    mod common;
+    use common::Health;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn journaled_world(history: bool) -> World<u16, u8, u8> {
+
+        let builder = World::<u16, u8, u8>::builder()
+            .with_journal_component::<Health, _>("Health")
+            .with_component_pointer(0u8, Health(50))
+            .with_journal(true);
+
+        match history {
+            true  => builder.with_history(1 << 20).build().unwrap(),
+            false => builder.build().unwrap(),
+        } // match ..
+    } // fn ..
+
+
+    #[test]
+    fn replay_keeps_shared_cells_shared() {
+
+        let mut world = journaled_world(false);
+        let pointer   = world.get_pointer_component::<Health>(0u8).unwrap().clone();
+        let first     = world.new_entity().with_component(Health(1)).unwrap().build();
+        let shared    = world.get_entity_component::<Health>(first).unwrap().unwrap().clone();
+        let second    = world.new_entity().with_shared_component(&shared).unwrap().build();
+        let third     = world.new_entity().with_shared_component(&pointer).unwrap().build();
+
+        let mut replayed = journaled_world(false);
+        let journal      = replayed.load_journal(&world.journal().unwrap().to_string()).unwrap();
+        replayed.replay_journal(&journal).unwrap();
+
+        let component = |entity| replayed.get_entity_component::<Health>(entity).unwrap().unwrap().clone();
+        assert!(Rc::ptr_eq(&component(first), &component(second)));
+        assert!(Rc::ptr_eq(&component(third), replayed.get_pointer_component::<Health>(0u8).unwrap()));
+        assert_eq!(component(second).borrow().0, 1);
+
+    } // fn ..
+
+
+    #[test]
+    fn failed_replay_leaves_the_world_untouched() {
+
+        let mut replayed = journaled_world(false);
+        let journal      = replayed.load_journal("mask 0 0\nnext 1\nassign 0 Health 3\n").unwrap();
+
+        let err = replayed.replay_journal(&journal).unwrap_err();
+        assert_eq!(err.kind(), EcsErrKind::InvalidJournal);
+        assert!(replayed.new_query().build().is_empty());
+
+    } // fn ..
+
+
+    #[test]
+    fn undo_without_history_is_an_error() {
+
+        let mut world = journaled_world(true);
+        world.new_entity().with_component(Health(1)).unwrap().build();
+        world.commit_step("spawn").unwrap();
+        world.undo().unwrap();
+
+        let mut replayed = journaled_world(false);
+        let journal      = replayed.load_journal(&world.journal().unwrap().to_string()).unwrap();
+
+        assert_eq!(replayed.replay_journal(&journal).unwrap_err().kind(), EcsErrKind::InvalidJournal);
+        assert!(replayed.new_query().build().is_empty());
+
+        let mut replayed = journaled_world(true);
+        replayed.replay_journal(&journal).unwrap();
+        assert!(replayed.new_query().build().is_empty());
+
+    } // fn ..
+
+
+    #[test]
+    fn failed_replay_rolls_back_committed_steps() {
+
+        let mut replayed = journaled_world(true);
+        let journal      = replayed.load_journal("mask 0 1\nnext 1\ninsert 0 Health 3\ncommit spawn\nmask 1 1\nnext 2\nundo\nundo\nundo\n").unwrap();
+
+        let err = replayed.replay_journal(&journal).unwrap_err();
+        assert_eq!(err.kind(), EcsErrKind::InvalidJournal);
+        assert!(replayed.new_query().build().is_empty());
+        assert_eq!(replayed.history().unwrap().undo_steps().count(), 0usize);
+        assert_eq!(replayed.history().unwrap().redo_steps().count(), 0usize);
+        assert!(replayed.journal().unwrap().is_empty());
+        assert!(replayed.validate().is_valid());
+
+        let spawned = replayed.new_entity().with_component(Health(7)).unwrap().build();
+        assert_eq!(replayed.new_query().build().get_entities(), vec![spawned]);
+
+    } // fn ..
+
+
+    #[test]
+    fn held_handles_are_not_replayed_as_shared() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_journal_component::<Health, _>("Health")
+            .with_journal(true)
+            .with_snapshots(2)
+            .build()
+            .unwrap();
+
+        let first  = world.new_entity().with_component(Health(1)).unwrap().build();
+        let handle = world.get_entity_component::<Health>(first).unwrap().unwrap().clone();
+        world.save_snapshot(0u64);
+
+        let second = world.new_entity().with_component(Health(1)).unwrap().build();
+        let third  = world.new_entity().with_shared_component(&handle).unwrap().build();
+        world.delete_entity(first).unwrap();
+        let fourth = world.new_entity().with_shared_component(&handle).unwrap().build();
+
+        let mut replayed = journaled_world(false);
+        let journal      = replayed.load_journal(&world.journal().unwrap().to_string()).unwrap();
+        replayed.replay_journal(&journal).unwrap();
+
+        let component = |entity| replayed.get_entity_component::<Health>(entity).unwrap().unwrap().clone();
+        assert!(!Rc::ptr_eq(&component(second), &component(third)));
+        assert!(Rc::ptr_eq(&component(third), &component(fourth)));
+        assert_eq!(replayed.new_query().build().get_entities().len(), 3usize);
+
+    } // fn ..
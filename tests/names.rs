@@ -0,0 +1,109 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::error::Error;
+
+    use hopper::{World, EcsErrKind};
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn named_world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_names(true)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    #[test]
+    fn entities_are_displayed_with_their_name() {
+
+        let mut world = named_world();
+        let player    = world.new_entity().with_name("player").unwrap().build();
+        let other     = world.new_entity().build();
+
+        assert_eq!(world.display(player).to_string(), "Entity(0, \"player\")");
+        assert_eq!(world.display(other).to_string(), "Entity(1)");
+        assert_eq!(format!("{:?}", player), "Entity(0, \"player\")");
+        assert_eq!(format!("{:?}", other), "Entity(1)");
+
+        let unnamed = World::<u16, u8, u8>::builder().build().unwrap();
+        assert_eq!(unnamed.display(player).to_string(), "Entity(0)");
+
+    } // fn ..
+
+
+    #[test]
+    fn world_errors_carry_the_names_of_their_entities() {
+
+        let mut world = named_world();
+        let player    = world.new_entity().with_name("player").unwrap().build();
+        let child     = world.new_entity().with_name("sword").unwrap().with_parent(player).unwrap().build();
+
+        let err = world.set_entity_parent(player, child).unwrap_err();
+        assert_eq!(err.kind(), EcsErrKind::CyclicParent);
+        assert_eq!(err.names(), &[(player, String::from("player")), (child, String::from("sword"))]);
+        assert_eq!(
+            err.to_string(),
+            "The operation `set_entity_parent` failed for `Entity(0, \"player\")`, `Entity(1, \"sword\")`!",
+        ); // assert_eq!()
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "The entity `Entity(0, \"player\")` cannot be parented to its descendant `Entity(1, \"sword\")`!",
+        ); // assert_eq!()
+
+        let twin = world.new_entity().build();
+        let err  = world.add_component_to_entity(hopper::Name(String::from("player")), twin).unwrap_err();
+        assert_eq!(err.kind(), EcsErrKind::DuplicateIndexKey);
+        assert!(err.source().unwrap().to_string().contains("`Entity(0, \"player\")`"));
+
+    } // fn ..
+
+
+    #[test]
+    fn error_messages_name_each_entity_separately() {
+
+        let mut world = named_world();
+        let entities  = (0..13).map(|_| world.new_entity().build()).collect::<Vec<_>>();
+
+        world.add_component_to_entity(hopper::Name(String::from("one")), entities[1]).unwrap();
+        world.add_component_to_entity(hopper::Name(String::from("twelve")), entities[12]).unwrap();
+        world.set_entity_parent(entities[12], entities[1]).unwrap();
+
+        let err = world.set_entity_parent(entities[1], entities[12]).unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "The entity `Entity(1, \"one\")` cannot be parented to its descendant `Entity(12, \"twelve\")`!",
+        ); // assert_eq!()
+
+    } // fn ..
+
+
+    #[test]
+    fn debug_names_follow_their_world() {
+
+        let mut world = named_world();
+        let player    = world.new_entity().with_name("player").unwrap().build();
+
+        world.modify_entity_component::<hopper::Name>(player, |name| name.0 = String::from("hero")).unwrap();
+        assert_eq!(format!("{:?}", player), "Entity(0, \"hero\")");
+
+        let mut other = named_world();
+        other.new_entity().with_name("villain").unwrap().build();
+        assert_eq!(format!("{:?}", player), "Entity(0)");
+
+        drop(other);
+        assert_eq!(format!("{:?}", player), "Entity(0, \"hero\")");
+
+        world.delete_entity_component::<hopper::Name>(player).unwrap();
+        assert_eq!(format!("{:?}", player), "Entity(0)");
+
+        world.add_component_to_entity(hopper::Name(String::from("player")), player).unwrap();
+        drop(world);
+        assert_eq!(format!("{:?}", player), "Entity(0)");
+
+    } // fn ..
@@ -0,0 +1,91 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::rc::Rc;
+
+    use hopper::{World, EcsErrKind};
+
+    mod common;
+    use common::Health;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_diff_component::<Health>()
+            .with_journal_component::<Health, _>("Health")
+            .with_journal(true)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    #[test]
+    fn patched_shared_components_are_replaced() {
+
+        let mut local  = world();
+        let first      = local.new_entity().with_component(Health(1)).unwrap().build();
+        let shared     = local.get_entity_component::<Health>(first).unwrap().unwrap().clone();
+        let second     = local.new_entity().with_shared_component(&shared).unwrap().build();
+
+        let mut remote = world();
+        remote.new_entity().with_component(Health(1)).unwrap().build();
+        remote.new_entity().with_component(Health(2)).unwrap().build();
+
+        local.apply_patch(&local.diff(&remote)).unwrap();
+
+        let component = |entity| local.get_entity_component::<Health>(entity).unwrap().unwrap().clone();
+        assert_eq!(component(first).borrow().0, 1);
+        assert_eq!(component(second).borrow().0, 2);
+        assert!(!Rc::ptr_eq(&component(first), &component(second)));
+
+    } // fn ..
+
+
+    #[test]
+    fn patched_components_are_journaled() {
+
+        let mut local  = world();
+        let entity     = local.new_entity().with_component(Health(1)).unwrap().build();
+
+        let mut remote = world();
+        remote.new_entity().with_component(Health(5)).unwrap().build();
+
+        local.apply_patch(&local.diff(&remote)).unwrap();
+
+        let mut replayed = world();
+        let journal      = replayed.load_journal(&local.journal().unwrap().to_string()).unwrap();
+        replayed.replay_journal(&journal).unwrap();
+
+        assert_eq!(replayed.get_entity_component::<Health>(entity).unwrap().unwrap().borrow().0, 5);
+
+    } // fn ..
+
+
+    #[test]
+    fn stale_spawns_are_rejected() {
+
+        let mut local  = world();
+        let entity     = local.new_entity().with_component(Health(1)).unwrap().build();
+
+        let mut remote = world();
+        remote.new_entity().with_component(Health(1)).unwrap().build();
+        let spawned    = remote.new_entity().with_component(Health(2)).unwrap().build();
+
+        let patch      = local.diff(&remote);
+        local.apply_patch(&patch).unwrap();
+        local.add_component_to_entity(Health(3), spawned).unwrap();
+
+        let err        = local.apply_patch(&patch).err().unwrap();
+        assert_eq!(err.kind(), EcsErrKind::ExistingEntity);
+        assert_eq!(err.entity(), Some(spawned));
+
+        let health     = |entity| local.get_entity_component::<Health>(entity).unwrap().unwrap().borrow().0;
+        assert_eq!(health(entity), 1);
+        assert_eq!(health(spawned), 3);
+
+    } // fn ..
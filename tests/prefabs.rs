@@ -0,0 +1,65 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, Prefab, Component};
+
+    mod common;
+    use common::{Health, world};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Unregistered;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Unregistered {}
+
+
+    #[test]
+    fn failed_spawn_prefab_leaves_no_trace() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_flag(0u8, 0..1)
+            .with_debug_validation(true)
+            .build()
+            .unwrap();
+
+        let broken = Prefab::new()
+            .with_component(Health(10))
+            .with_child(Prefab::new().with_component(Health(1)))
+            .with_flag(1u8, None);
+
+        assert!(world.spawn_prefab(&broken).is_err());
+        assert!(world.new_query().build().get_entities().is_empty());
+        assert!(world.validate().is_valid());
+
+        let entity = world.spawn_prefab(&Prefab::new().with_component(Health(5))).unwrap();
+        assert_eq!(world.new_query().build().get_entities(), vec![entity]);
+        assert!(world.validate().is_valid());
+
+    } // fn ..
+
+
+    #[test]
+    fn failed_with_prefab_keeps_the_world_untouched() {
+
+        let mut world = world();
+
+        let broken = Prefab::new()
+            .with_child(Prefab::new().with_component(Health(1)))
+            .with_component(Unregistered);
+
+        assert!(world.new_entity().with_prefab(&broken).is_err());
+        assert!(world.new_query().build().get_entities().is_empty());
+        assert!(world.validate().is_valid());
+
+    } // fn ..
@@ -0,0 +1,218 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    use hopper::{World, Entity, EcsErrKind};
+
+    mod common;
+    use common::{Health, world};
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    #[test]
+    fn borrowed_components_are_found_by_entity() {
+
+        let mut world = world();
+        let entities  = (0..64)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<hopper::Entity>>();
+
+        let query = world.new_query().write::<Health>().unwrap().build();
+        let mut components = query.components_mut::<Health>().unwrap();
+
+        components.get_mut(entities[42]).unwrap().0 += 100;
+        assert_eq!(components.get_mut(entities[42]).unwrap().0, 142);
+        drop(components);
+
+        let components = query.components::<Health>().unwrap();
+        assert_eq!(components.get(entities[7]).as_deref(), Some(&Health(7)));
+        assert_eq!(components.get(entities[42]).as_deref(), Some(&Health(142)));
+
+    } // fn ..
+
+
+    #[test]
+    fn borrowed_components_are_checked_up_front() {
+
+        let mut world = world();
+        let entity    = world.new_entity().with_component(Health(1)).unwrap().build();
+        let handle    = world.get_entity_component::<Health>(entity).unwrap().unwrap().clone();
+
+        let query     = world.new_query().write::<Health>().unwrap().build();
+        let borrowed  = handle.borrow_mut();
+        assert_eq!(query.components::<Health>().err().unwrap().kind(), EcsErrKind::BorrowConflict);
+        assert_eq!(query.components_mut::<Health>().err().unwrap().kind(), EcsErrKind::BorrowConflict);
+        drop(borrowed);
+
+        let components = query.components_mut::<Health>().unwrap();
+        assert_eq!(query.components::<Health>().err().unwrap().kind(), EcsErrKind::BorrowConflict);
+        assert_eq!(query.components_mut::<Health>().err().unwrap().kind(), EcsErrKind::BorrowConflict);
+        drop(components);
+
+        let first  = query.components::<Health>().unwrap();
+        let second = query.components::<Health>().unwrap();
+        assert_eq!(first.get(entity).as_deref(), second.get(entity).as_deref());
+        assert!(query.get_components::<Health>().unwrap().all(|health| health.borrow().0 == 1));
+
+    } // fn ..
+
+
+    #[test]
+    fn borrowing_components_requires_a_declared_access() {
+
+        let mut world = world();
+        world.new_entity().with_component(Health(1)).unwrap().build();
+
+        let query = world.new_query().named("health").with_component::<Health>().unwrap().build();
+        assert_eq!(query.components::<Health>().err().unwrap().kind(), EcsErrKind::UndeclaredAccess);
+        assert_eq!(query.components_mut::<Health>().err().unwrap().kind(), EcsErrKind::UndeclaredAccess);
+        assert_eq!(query.par_for_each::<Health>(1, |_, health| health.0 += 1).err().unwrap().kind(), EcsErrKind::UndeclaredAccess);
+
+        let query = world.new_query().named("health").read::<Health>().unwrap().build();
+        assert!(query.components::<Health>().is_ok());
+        assert_eq!(
+            query.components_mut::<Health>().err().unwrap().to_string(),
+            "The query `health` did not declare a write access to the component `queries::common::Health`!",
+        ); // assert_eq!()
+
+    } // fn ..
+
+
+    #[test]
+    fn cached_queries_register_their_access() {
+
+        let mut world = world();
+        world.new_entity().with_component(Health(1)).unwrap().build();
+
+        let mut state = world.new_query().named("regen").write::<Health>().unwrap().into_state();
+        let live      = world.new_query().named("render").read::<Health>().unwrap().build();
+
+        let err = state.query(&world).err().unwrap();
+        assert_eq!(err.kind(), EcsErrKind::BorrowConflict);
+        drop(live);
+
+        let query = state.query(&world).unwrap();
+        assert!(world.new_query().named("render").read::<Health>().is_err());
+
+        for (_, mut health) in query.components_mut::<Health>().unwrap().iter_mut() { health.0 += 1; }
+        assert_eq!(query.count(), 1usize);
+
+    } // fn ..
+
+
+    #[test]
+    fn cached_queries_match_fresh_queries() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_flag(0u8, 0..1)
+            .build()
+            .unwrap();
+
+        let fresh = |world: &World<u16, u8, u8>| {
+
+            let mut entities = world.new_query().with_component::<Health>().unwrap().with_flag(0u8, None).unwrap().build().get_entities();
+            entities.sort();
+            entities
+
+        }; // let ..
+
+        let mut state = world.new_query().with_component::<Health>().unwrap().with_flag(0u8, None).unwrap().into_state();
+        let entities  = (0..32)
+            .map(|index| world.new_entity().with_component(Health(index)).unwrap().build())
+            .collect::<Vec<Entity>>();
+
+        for entity in entities.iter().step_by(2) { world.set_entity_flag(*entity, 0u8, None).unwrap(); }
+        assert_eq!(state.query(&world).unwrap().get_entities(), fresh(&world));
+        assert_eq!(state.len(), 16usize);
+
+        world.delete_entity(entities[0]).unwrap();
+        world.delete_entity_component::<Health>(entities[2]).unwrap();
+        world.remove_entity_flag(entities[4], 0u8, None).unwrap();
+        world.set_entity_flag(entities[5], 0u8, None).unwrap();
+        world.disable_entity(entities[6]).unwrap();
+        world.new_entity().with_component(Health(32)).unwrap().build();
+        let flagged = world.new_entity().with_component(Health(33)).unwrap().build();
+        world.set_entity_flag(flagged, 0u8, None).unwrap();
+        assert_eq!(state.query(&world).unwrap().get_entities(), fresh(&world));
+
+        world.enable_entity(entities[6]).unwrap();
+        for _ in 0..64 {
+            for entity in entities.iter().skip(1) {
+
+                world.set_entity_flag(*entity, 0u8, None).unwrap();
+                world.remove_entity_flag(*entity, 0u8, None).unwrap();
+
+            } // for ..
+        } // for ..
+
+        world.set_entity_flag(entities[9], 0u8, None).unwrap();
+        assert_eq!(state.query(&world).unwrap().get_entities(), fresh(&world));
+        assert_eq!(fresh(&world).len(), 2usize);
+
+    } // fn ..
+
+
+    #[test]
+    fn cached_queries_belong_to_their_world() {
+
+        let other     = world();
+        let world     = world();
+        let mut state = world.new_query().named("health").with_component::<Health>().unwrap().into_state();
+
+        assert!(state.query(&world).is_ok());
+        assert_eq!(state.update(&other).err().unwrap().kind(), EcsErrKind::ForeignQueryState);
+        assert_eq!(
+            state.query(&other).err().unwrap().to_string(),
+            "The query state `health` was built from another world!",
+        ); // assert_eq!()
+
+    } // fn ..
+
+
+    #[test]
+    fn parallel_queries_visit_every_component_once() {
+
+        let mut parallel = world();
+        let mut serial   = world();
+        for index in 0..1000 {
+
+            parallel.new_entity().with_component(Health(index)).unwrap().build();
+            serial.new_entity().with_component(Health(index)).unwrap().build();
+
+        } // for ..
+
+        let query  = parallel.new_query().write::<Health>().unwrap().build();
+        let visits = Mutex::new(Vec::<Entity>::new());
+        query.par_for_each::<Health>(7, |entity, health| {
+
+            health.0 = health.0 * 3 + 1;
+            visits.lock().unwrap().push(entity);
+
+        }).unwrap(); // par_for_each()
+
+        let mut visits = visits.into_inner().unwrap();
+        visits.sort();
+        assert_eq!(visits, { let mut entities = query.get_entities(); entities.sort(); entities });
+
+        let serial_query = serial.new_query().write::<Health>().unwrap().build();
+        for (_, mut health) in serial_query.components_mut::<Health>().unwrap().iter_mut() { health.0 = health.0 * 3 + 1; }
+
+        let mut parallel_values = query.components::<Health>().unwrap().iter().map(|(entity, health)| (entity, health.0)).collect::<Vec<(Entity, i32)>>();
+        let mut serial_values   = serial_query.components::<Health>().unwrap().iter().map(|(entity, health)| (entity, health.0)).collect::<Vec<(Entity, i32)>>();
+        parallel_values.sort();
+        serial_values.sort();
+        assert_eq!(parallel_values, serial_values);
+
+        let threads = Mutex::new(HashSet::<ThreadId>::new());
+        query.par_for_each::<Health>(1000, |_, _| { threads.lock().unwrap().insert(thread::current().id()); }).unwrap();
+        assert_eq!(threads.into_inner().unwrap().len(), 1usize);
+
+    } // fn ..
@@ -0,0 +1,139 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::cell::Cell;
+    use std::error::Error;
+
+    use hopper::{World, Component, SceneComponent};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Label(String);
+
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Flaky;
+
+
+    thread_local! {
+        static FLAKY_PARSES: Cell<usize> = const { Cell::new(0usize) };
+    } // thread_local!
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Label {}
+    impl Component for Flaky {}
+
+
+    impl SceneComponent for Label {
+        fn from_scene(value: &str) -> Option<Self> { Some(Label(value.to_string())) }
+    } // impl ..
+
+
+    impl SceneComponent for Flaky {
+        fn from_scene(_: &str) -> Option<Self> {
+
+            let parses = FLAKY_PARSES.with(|parses| parses.replace(parses.get() + 1usize));
+            (parses == 0usize).then_some(Flaky)
+
+        } // fn ..
+    } // impl ..
+
+
+    fn world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_scene_component::<Label, _>("Label")
+            .with_flag(0u8, 0..1)
+            .with_flag(1u8, 1..2)
+            .with_debug_validation(true)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    #[test]
+    fn escaped_hash_is_kept_in_values() {
+
+        let mut world = world();
+        let scene     = world.load_scene("entity a\n    component Label \\#1 # first\n").unwrap();
+        let label     = world.get_entity_component::<Label>(scene.entity("a").unwrap()).unwrap().unwrap();
+
+        assert_eq!(*label.borrow(), Label(String::from("#1")));
+
+    } // fn ..
+
+
+    #[test]
+    fn failed_reload_leaves_world_and_scene_untouched() {
+
+        let mut world = world();
+        let mut scene = world.load_scene("entity a\n    component Label first\n").unwrap();
+        let a         = scene.entity("a").unwrap();
+
+        let broken = "entity a\n    component Label second\nentity b\n    component Label third\n    flag 3\n";
+        assert!(world.reload_scene(&mut scene, broken).is_err());
+
+        assert_eq!(scene.entity("b"), None);
+        assert_eq!(world.new_query().build().get_entities(), vec![a]);
+        assert_eq!(*world.get_entity_component::<Label>(a).unwrap().unwrap().borrow(), Label(String::from("first")));
+        assert!(world.validate().is_valid());
+
+        world.reload_scene(&mut scene, "entity a\n    component Label second\n").unwrap();
+        assert_eq!(*world.get_entity_component::<Label>(a).unwrap().unwrap().borrow(), Label(String::from("second")));
+
+    } // fn ..
+
+
+    #[test]
+    fn reload_keeps_disabled_entities_disabled() {
+
+        let mut world = world();
+        let mut scene = world.load_scene("entity a\n    component Label first\n    flag 0\n").unwrap();
+        let a         = scene.entity("a").unwrap();
+
+        world.disable_entity(a).unwrap();
+        world.reload_scene(&mut scene, "entity a\n    component Label second\n").unwrap();
+
+        assert!(world.is_entity_disabled(a).unwrap());
+        assert!(!world.entity_has_flag(a, 0u8, None).unwrap());
+        assert!(world.new_query().build().is_empty());
+
+    } // fn ..
+
+
+    #[test]
+    fn reload_only_clears_the_flags_of_the_scene() {
+
+        let mut world = world();
+        let mut scene = world.load_scene("entity a\n    flag 0\n").unwrap();
+        let a         = scene.entity("a").unwrap();
+
+        world.set_entity_flag(a, 1u8, None).unwrap();
+        world.reload_scene(&mut scene, "entity a\n").unwrap();
+
+        assert!(!world.entity_has_flag(a, 0u8, None).unwrap());
+        assert!(world.entity_has_flag(a, 1u8, None).unwrap());
+
+    } // fn ..
+
+
+    #[test]
+    fn failed_component_values_report_their_line() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_scene_component::<Flaky, _>("Flaky")
+            .build()
+            .unwrap();
+
+        let err = world.load_scene("\nentity a\n    component Flaky x\n").err().unwrap();
+        assert_eq!(err.source().unwrap().to_string(), "The scene is invalid at line 3: invalid component value `x`!");
+
+    } // fn ..
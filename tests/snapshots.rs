@@ -0,0 +1,119 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    use hopper::{World, Component};
+
+    mod common;
+    use common::{Health, health};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Velocity(f32, f32);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Velocity {}
+
+
+    fn journaled_world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_journal_component::<Health, _>("Health")
+            .with_diff_component::<Health>()
+            .with_component_pointer(0u8, Health(50))
+            .with_journal(true)
+            .with_snapshots(4)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    #[test]
+    fn restored_values_are_journaled() {
+
+        let mut world = journaled_world();
+        let entity    = world.new_entity().with_component(Health(1)).unwrap().build();
+
+        world.save_snapshot(0u64);
+        world.modify_entity_component::<Health>(entity, |health| health.0 = 2).unwrap();
+        world.set_pointer_component(0u8, Health(60)).unwrap();
+        world.restore_snapshot(0u64).unwrap();
+
+        assert!(world.journal().unwrap().to_string().contains("assign 0 Health 1"));
+
+        let mut replayed = journaled_world();
+        let journal      = replayed.load_journal(&world.journal().unwrap().to_string()).unwrap();
+        replayed.replay_journal(&journal).unwrap();
+
+        assert_eq!(health(&replayed, entity), 1);
+        assert_eq!(replayed.get_pointer_component::<Health>(0u8).unwrap().borrow().0, 50);
+        assert_eq!(replayed.state_hash(), world.state_hash());
+
+    } // fn ..
+
+
+    #[test]
+    fn respawned_entities_keep_shared_cells() {
+
+        let mut world = journaled_world();
+        let pointer   = world.get_pointer_component::<Health>(0u8).unwrap().clone();
+        let first     = world.new_entity().with_component(Health(1)).unwrap().build();
+        let shared    = world.get_entity_component::<Health>(first).unwrap().unwrap().clone();
+        let second    = world.new_entity().with_shared_component(&shared).unwrap().build();
+        let third     = world.new_entity().with_shared_component(&pointer).unwrap().build();
+
+        world.save_snapshot(0u64);
+        world.delete_entity_group(&[first, second, third]).unwrap();
+        world.restore_snapshot(0u64).unwrap();
+
+        let component = |entity| world.get_entity_component::<Health>(entity).unwrap().unwrap().clone();
+        assert!(Rc::ptr_eq(&component(first), &component(second)));
+        assert!(Rc::ptr_eq(&component(third), &pointer));
+        assert!(world.validate().is_valid());
+
+    } // fn ..
+
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "timings are only meaningful in release builds")]
+    fn save_and_restore_fit_a_60hz_frame() {
+
+        let mut world = World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_component::<Velocity>()
+            .with_snapshots(8)
+            .build()
+            .unwrap();
+
+        let entities = (0..4096)
+            .map(|index| world
+                .new_entity()
+                .with_component(Health(index)).unwrap()
+                .with_component(Velocity(index as f32, 0.0)).unwrap()
+                .build()
+            ).collect::<Vec<hopper::Entity>>();
+
+        let start = Instant::now();
+        for tick in 0..60u64 {
+
+            world.save_snapshot(tick);
+            for entity in entities.iter().step_by(16) { world.modify_entity_component::<Health>(*entity, |health| health.0 += 1).unwrap(); }
+            world.restore_snapshot(tick).unwrap();
+
+        } // for ..
+
+        assert_eq!(health(&world, entities[0]), 0);
+        let frame = start.elapsed() / 60u32;
+        assert!(frame < Duration::from_micros(16_667), "saving and restoring 4096 entities took {:?} per frame", frame);
+
+    } // fn ..
@@ -0,0 +1,97 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use hopper::{World, EcsErr};
+
+    mod common;
+    use common::{Health, health};
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    fn world() -> World<u16, u8, u8> {
+        World::<u16, u8, u8>::builder()
+            .with_component::<Health>()
+            .with_flag(0u8, 0..1)
+            .with_debug_validation(true)
+            .build()
+            .unwrap()
+    } // fn ..
+
+
+    #[test]
+    fn rollback_undoes_spawns_deletes_and_flags() {
+
+        let mut world = world();
+        let kept      = world.new_entity().with_component(Health(1)).unwrap().build();
+        let deleted   = world.new_entity().with_component(Health(2)).unwrap().build();
+
+        let result = world.transaction(|world| {
+
+            world.new_entity().with_component(Health(3))?.build();
+            world.delete_entity(deleted)?;
+            world.set_entity_flag(kept, 0u8, None)?;
+
+            Err::<(), _>(EcsErr::MissingEntity(kept))
+
+        }); // let ..
+
+        assert!(result.is_err());
+
+        let mut entities = world.new_query().build().get_entities();
+        entities.sort();
+
+        assert_eq!(entities, vec![kept, deleted]);
+        assert_eq!(health(&world, deleted), 2);
+        assert!(world.new_query().with_flag(0u8, None).unwrap().build().is_empty());
+        assert!(world.validate().is_valid());
+
+    } // fn ..
+
+
+    #[test]
+    fn rollback_undoes_edits_made_through_borrowed_components() {
+
+        let mut world = world();
+        let first     = world.new_entity().with_component(Health(1)).unwrap().build();
+        let second    = world.new_entity().with_component(Health(2)).unwrap().build();
+
+        let result = world.transaction(|world| {
+
+            world.get_entity_component::<Health>(first)?.unwrap().borrow_mut().0 = 10;
+
+            let query = world.new_query().write::<Health>()?.build();
+            for (_, mut health) in query.components_mut::<Health>()?.iter_mut() { health.0 += 100; }
+            query.par_for_each::<Health>(1, |_, health| health.0 += 1000)?;
+
+            Err::<(), _>(EcsErr::MissingEntity(first))
+
+        }); // let ..
+
+        assert!(result.is_err());
+        assert_eq!(health(&world, first), 1);
+        assert_eq!(health(&world, second), 2);
+
+    } // fn ..
+
+
+    #[test]
+    fn committed_edits_made_through_borrowed_components_are_kept() {
+
+        let mut world = world();
+        let entity    = world.new_entity().with_component(Health(1)).unwrap().build();
+
+        world.transaction(|world| {
+
+            world.get_entity_component::<Health>(entity)?.unwrap().borrow_mut().0 = 10;
+            Ok(())
+
+        }).unwrap();
+
+        assert_eq!(health(&world, entity), 10);
+
+    } // fn ..
+